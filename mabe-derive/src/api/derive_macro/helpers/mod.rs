@@ -0,0 +1,1113 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+mod finders;
+use crate::error::api::Error;
+use finders::*;
+use proc_macro2::{Span, TokenStream};
+use syn::{
+    parse_quote, Attribute, DeriveInput, Expr, ExprLit, ExprUnary, Field, GenericArgument, Ident, Lit, LitStr, Meta,
+    NestedMeta, Path, PathArguments, Type, UnOp, Variant,
+};
+
+/// The policy applied to the fields of the variants that are not interpolated in the error or debug message.
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum UnusedFields {
+    /// The unused fields are accepted.
+    Allow,
+    /// The unused fields are accepted, but a compiler warning is emitted for each of them.
+    Warn,
+    /// The unused fields are rejected with a compile-time error.
+    #[default]
+    Deny,
+}
+
+/// The severity of a variant, which mirrors the `Severity` enum of the runtime crate.
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum Severity {
+    /// A note about a noteworthy situation.
+    Note,
+    /// A warning about a potential problem.
+    Warning,
+    /// An error from which the program can recover.
+    #[default]
+    Error,
+    /// An error from which the program cannot recover.
+    Fatal,
+}
+
+impl Severity {
+    /// Returns the label of the severity (i.e. the value of the `severity` option), which is also used in the prefix of the
+    /// error line.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Note => "note",
+            Self::Warning => "warning",
+            Self::Error => "error",
+            Self::Fatal => "fatal",
+        }
+    }
+
+    /// Returns the identifier of the variant of the `Severity` enum of the runtime crate that matches the severity.
+    pub fn ident(&self) -> Ident {
+        let label = self.label();
+        Ident::new(&format!("{}{}", label[..1].to_uppercase(), &label[1..]), Span::call_site())
+    }
+
+    /// Returns all the severities, from the least to the most severe.
+    pub fn all() -> [Self; 4] {
+        [Self::Note, Self::Warning, Self::Error, Self::Fatal]
+    }
+}
+
+/// A tool that returns the severity that has the given label. The `opt` and `ident` parameters are the option and the
+/// identifier of the enum or variant that the label was found on, and are only used in the error messages. The function will
+/// panic if the label is not `"note"`, `"warning"`, `"error"`, or `"fatal"`.
+fn parse_severity(label: &str, opt: &String, ident: &Ident) -> Severity {
+    Severity::all()
+        .into_iter()
+        .find(|severity| severity.label() == label)
+        .unwrap_or_else(|| panic!("{}", Error::InvalidOptValue(opt, label, ident)))
+}
+
+/// The options that can be set on an enum through the `mabe` attribute.
+pub struct EnumOpts {
+    /// The path of the `mabe` crate, which the generated code uses to refer to the runtime items of the crate. It can be
+    /// overridden when `mabe` is re-exported by another crate (e.g. `#[mabe(crate = "errors::mabe")]`).
+    pub crate_path: Path,
+    /// Whether the generated code only depends on the `core` and `alloc` crates (i.e. not on the `std` crate).
+    pub no_std: bool,
+    /// Whether the generated code only depends on the `core` crate, in which case the methods that return a `String` are not
+    /// generated. This option implies the `no_std` option.
+    pub no_alloc: bool,
+    /// The policy applied to the fields of the variants that are not interpolated in the error or debug message.
+    pub unused_fields: UnusedFields,
+    /// Whether a fieldless `Kind` enum (with one unit variant per variant of the enum) is generated alongside the enum.
+    pub kind: bool,
+    /// Whether the `FromStr` implementation, which parses the unit variants from their names and codes, is generated.
+    pub from_str: bool,
+    /// Whether the `TryFrom<u16>` and `TryFrom<i32>` implementations, which convert the integer codes of the unit variants into
+    /// the variants, and the `as_code` method are generated.
+    pub try_from_code: bool,
+    /// The prefix of the numeric codes of the variants. If it is set, the variants that don't have a code are numbered after
+    /// their discriminants.
+    pub code_prefix: Option<String>,
+    /// The minimum number of digits of the numeric codes of the variants, which are padded with zeros.
+    pub code_width: usize,
+    /// Whether the code of the variant is displayed in the error line of the `Display` implementation.
+    pub show_code: bool,
+    /// The template of the help URLs of the variants that don't have one (e.g. `"https://docs.example.com/errors/{code}"`).
+    pub docs_base: Option<String>,
+    /// Whether the help URL of the variant is displayed in a `[see]` line of the `Display` implementation.
+    pub show_url: bool,
+    /// The severity of the variants that don't have one.
+    pub severity: Severity,
+}
+
+impl Default for EnumOpts {
+    fn default() -> Self {
+        Self {
+            crate_path: parse_quote! { ::mabe },
+            no_std: false,
+            no_alloc: false,
+            unused_fields: UnusedFields::default(),
+            kind: false,
+            from_str: false,
+            try_from_code: false,
+            code_prefix: None,
+            code_width: 4,
+            show_code: false,
+            docs_base: None,
+            show_url: false,
+            severity: Severity::default(),
+        }
+    }
+}
+
+/// The code of a variant, as set through the `code` option of its `mabe` attribute.
+pub enum VariantCode {
+    /// A code that is used as is (e.g. `#[mabe(code = "AUTH-0003")]`).
+    Text(String),
+    /// A code that is prefixed and padded according to the options of the enum (e.g. `#[mabe(code = 3)]`).
+    Number(u64),
+}
+
+/// The options that can be set on a variant through the `mabe` attribute.
+#[derive(Default)]
+pub struct VariantOpts {
+    /// Whether the constructor of the variant is not generated (e.g. because its name is already used by a method of the enum).
+    pub no_constructor: bool,
+    /// Whether the `is_*` and `as_*` methods of the variant are not generated (e.g. because their names are already used).
+    pub no_accessors: bool,
+    /// The code of the variant.
+    pub code: Option<VariantCode>,
+    /// The template of the help URL of the variant, which overrides the `docs_base` option of the enum.
+    pub url: Option<String>,
+    /// The severity of the variant, which overrides the `severity` option of the enum.
+    pub severity: Option<Severity>,
+}
+
+/// The options that can be set on a field of a variant through the `mabe` attribute.
+#[derive(Default)]
+pub struct FieldOpts {
+    /// The text that replaces the value of the field when it is rendered, if the field is redacted.
+    pub redact: Option<String>,
+    /// Whether the field may be interpolated in the error and debug messages even though it is redacted.
+    pub interpolate: bool,
+    /// Whether the field may be left out of the error and debug messages.
+    pub skip: bool,
+    /// Whether the field holds the source code that the spans of the labeled fields of the variant refer to.
+    pub source_code: bool,
+    /// The text of the label that is rendered under the span held by the field, if the field is labeled.
+    pub label: Option<String>,
+    /// Whether the field holds the error that caused the variant (i.e. the value returned by the `source` method).
+    pub source: bool,
+    /// Whether the field holds the backtrace of the variant, which is captured when the variant is created from its source.
+    pub backtrace: bool,
+    /// Whether the field holds the location where the variant was created, which is recorded by the generated constructors.
+    pub location: bool,
+    /// Whether the variant can be created from the field (which is then its source) with a generated `From` implementation.
+    pub from: bool,
+}
+
+/// A tool that returns the message of the attribute of a variant. The function will panic in the following cases: if the
+/// attribute is not `error` or `debug`, if the attribute doesn't have exactly one argument, if the argument of the attribute is
+/// not a string literal, if the `error` attribute is not found, or if the attribute is used more than once on the same variant.
+pub fn get_msg<A: ToString>(attribute: A, variant: &Variant) -> String {
+    let attribute = attribute.to_string();
+
+    if attribute != "error" && attribute != "debug" {
+        panic!("{}", Error::InvalidAttr(&attribute, "helpers::get_msg"));
+    }
+
+    let filtered_attrs = variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident(&attribute))
+        .filter_map(|attr| {
+            let mut msg = String::new();
+
+            if let Ok(Meta::List(meta_list)) = attr.parse_meta() {
+                if let Some(attr_ident) = meta_list.path.get_ident() {
+                    if *attr_ident == attribute {
+                        if meta_list.nested.len() != 1 {
+                            panic!("{}", Error::UnexpectedAttrArgs(&attribute, meta_list.nested.len(), &variant.ident));
+                        }
+
+                        if let NestedMeta::Lit(Lit::Str(lit_str)) = &meta_list.nested[0] {
+                            if lit_str.value().is_empty() {
+                                panic!("{}", Error::EmptyAttr(&attribute, &variant.ident));
+                            }
+
+                            msg = lit_str.value();
+                        } else {
+                            panic!("{}", Error::UnsupportedAttrArg(&attribute, &variant.ident));
+                        }
+                    }
+                }
+            } else {
+                panic!("{}", Error::AttrParsingFailed(&variant.ident));
+            }
+
+            if msg.is_empty() {
+                None
+            } else {
+                Some(msg)
+            }
+        })
+        .collect::<Vec<String>>();
+
+    if filtered_attrs.len() > 1 {
+        panic!("{}", Error::ExcessAttr(&attribute, &variant.ident));
+    }
+
+    if filtered_attrs.is_empty() && attribute == "error" {
+        panic!("{}", Error::ErrAttrNotFound(&variant.ident));
+    }
+
+    if filtered_attrs.is_empty() {
+        String::new()
+    } else {
+        filtered_attrs[0].clone()
+    }
+}
+
+/// A tool that returns the explanation of a variant, which is the expression of its `explain` attribute (e.g. a string literal
+/// or an `include_str!` invocation), or `None` if the variant doesn't have one. The expression is returned as is, so that the
+/// compiler checks its type in the generated code. The function will panic in the following cases: if the attribute doesn't
+/// have an argument, if its argument is an empty string literal, or if the attribute is used more than once on the same
+/// variant.
+pub fn get_explanation(variant: &Variant) -> Option<TokenStream> {
+    let attribute = "explain".to_string();
+
+    let mut explanations = variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident(&attribute))
+        .map(|attr| {
+            let expr =
+                attr.parse_args::<TokenStream>().unwrap_or_else(|_| panic!("{}", Error::AttrParsingFailed(&variant.ident)));
+
+            match syn::parse2::<LitStr>(expr.clone()) {
+                Ok(lit_str) if lit_str.value().is_empty() => panic!("{}", Error::EmptyAttr(&attribute, &variant.ident)),
+                _ if expr.is_empty() => panic!("{}", Error::UnexpectedAttrArgs(&attribute, 0, &variant.ident)),
+                _ => expr,
+            }
+        })
+        .collect::<Vec<TokenStream>>();
+
+    if explanations.len() > 1 {
+        panic!("{}", Error::ExcessAttr(&attribute, &variant.ident));
+    }
+
+    explanations.pop()
+}
+
+/// A tool that returns the options of the `mabe` attributes found in a list of attributes, as pairs of option names and optional
+/// values. The `ident` parameter is the identifier of the enum or variant the attributes belong to, and is only used in the error
+/// messages. The function will panic in the following cases: if an attribute cannot be parsed, or if an option is used more than
+/// once.
+pub fn get_opts(attrs: &[Attribute], ident: &Ident) -> Vec<(String, Option<Lit>)> {
+    let mut opts = Vec::<(String, Option<Lit>)>::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("mabe")) {
+        let nested = match attr.parse_meta() {
+            Ok(Meta::List(meta_list)) => meta_list.nested,
+            _ => panic!("{}", Error::AttrParsingFailed(ident)),
+        };
+
+        for meta in nested {
+            let (opt, value) = match meta {
+                NestedMeta::Meta(Meta::Path(path)) => (path_to_string(&path), None),
+                NestedMeta::Meta(Meta::NameValue(name_value)) => (path_to_string(&name_value.path), Some(name_value.lit)),
+                _ => panic!("{}", Error::AttrParsingFailed(ident)),
+            };
+
+            if opts.iter().any(|(found_opt, _)| *found_opt == opt) {
+                panic!("{}", Error::ExcessOpt(&opt, ident));
+            }
+
+            opts.push((opt, value));
+        }
+    }
+
+    opts
+}
+
+/// A tool that returns the options set through the `mabe` attribute on an enum. The function will panic in the following cases:
+/// if the attribute cannot be parsed, if an option is not supported, if an option is used more than once, if the value of an
+/// option is not valid, or if a conversion option is used with the `no_alloc` option.
+pub fn get_enum_opts(input: &DeriveInput) -> EnumOpts {
+    let mut opts = EnumOpts::default();
+
+    for (opt, value) in get_opts(&input.attrs, &input.ident) {
+        match (opt.as_str(), value) {
+            ("unused_fields", Some(Lit::Str(lit_str))) => {
+                opts.unused_fields = match lit_str.value().as_str() {
+                    "allow" => UnusedFields::Allow,
+                    "warn" => UnusedFields::Warn,
+                    "deny" => UnusedFields::Deny,
+                    value => panic!("{}", Error::InvalidOptValue(&opt, value, &input.ident)),
+                }
+            }
+            ("crate", Some(Lit::Str(lit_str))) => {
+                opts.crate_path = lit_str
+                    .parse::<Path>()
+                    .unwrap_or_else(|_| panic!("{}", Error::InvalidOptValue(&opt, &lit_str.value(), &input.ident)));
+            }
+            ("no_std", None) => opts.no_std = true,
+            ("no_alloc", None) => {
+                opts.no_std = true;
+                opts.no_alloc = true;
+            }
+            ("kind", None) => opts.kind = true,
+            ("from_str", None) => opts.from_str = true,
+            ("try_from_code", None) => opts.try_from_code = true,
+            ("code_prefix", Some(Lit::Str(lit_str))) => opts.code_prefix = Some(lit_str.value()),
+            ("code_width", Some(Lit::Int(lit_int))) => {
+                opts.code_width = lit_int
+                    .base10_parse::<usize>()
+                    .unwrap_or_else(|_| panic!("{}", Error::InvalidOptValue(&opt, &lit_int.to_string(), &input.ident)));
+            }
+            ("show_code", None) => opts.show_code = true,
+            ("docs_base", Some(Lit::Str(lit_str))) => opts.docs_base = Some(lit_str.value()),
+            ("show_url", None) => opts.show_url = true,
+            ("severity", Some(Lit::Str(lit_str))) => opts.severity = parse_severity(&lit_str.value(), &opt, &input.ident),
+            (
+                "crate" | "unused_fields" | "no_std" | "no_alloc" | "kind" | "from_str" | "try_from_code" | "code_prefix"
+                | "code_width" | "show_code" | "docs_base" | "show_url" | "severity",
+                _,
+            ) => panic!("{}", Error::UnsupportedOptArg(&opt, &input.ident)),
+            _ => panic!("{}", Error::UnsupportedOpt(&opt, &input.ident)),
+        }
+    }
+
+    // The conversions fail with a `ConversionError`, which is only available with an allocator.
+    for (opt, set) in [("from_str", opts.from_str), ("try_from_code", opts.try_from_code)] {
+        if set && opts.no_alloc {
+            panic!("{}", Error::ConversionWithoutAlloc(&opt.to_string(), &input.ident));
+        }
+    }
+
+    opts
+}
+
+/// A tool that returns the options set through the `mabe` attribute on a variant. The function will panic in the following
+/// cases: if the attribute cannot be parsed, if an option is not supported, if an option is used more than once, or if the code
+/// of the variant is neither a non-empty string literal nor a non-negative integer literal, or if the help URL of the variant
+/// or the severity of the variant is not a valid string literal.
+pub fn get_variant_opts(variant: &Variant) -> VariantOpts {
+    let mut opts = VariantOpts::default();
+
+    for (opt, value) in get_opts(&variant.attrs, &variant.ident) {
+        match (opt.as_str(), value) {
+            ("code", Some(Lit::Str(lit_str))) => match lit_str.value() {
+                code if code.is_empty() => panic!("{}", Error::InvalidOptValue(&opt, &code, &variant.ident)),
+                code => opts.code = Some(VariantCode::Text(code)),
+            },
+            ("code", Some(Lit::Int(lit_int))) => {
+                let code = lit_int
+                    .base10_parse::<u64>()
+                    .unwrap_or_else(|_| panic!("{}", Error::InvalidOptValue(&opt, &lit_int.to_string(), &variant.ident)));
+                opts.code = Some(VariantCode::Number(code));
+            }
+            ("url", Some(Lit::Str(lit_str))) => opts.url = Some(lit_str.value()),
+            ("severity", Some(Lit::Str(lit_str))) => {
+                opts.severity = Some(parse_severity(&lit_str.value(), &opt, &variant.ident));
+            }
+            ("no_constructor", None) => opts.no_constructor = true,
+            ("no_accessors", None) => opts.no_accessors = true,
+            ("code" | "url" | "severity" | "no_constructor" | "no_accessors", _) => {
+                panic!("{}", Error::UnsupportedOptArg(&opt, &variant.ident))
+            }
+            _ => panic!("{}", Error::UnsupportedOpt(&opt, &variant.ident)),
+        }
+    }
+
+    opts
+}
+
+/// A tool that returns the integer code of each variant of an enum, or `None` for the variants that don't have one. The integer
+/// code of a variant is its numeric `code` option, or its discriminant otherwise (i.e. its explicit discriminant, or the
+/// discriminant of the previous variant plus one). The implicit discriminants are only used if the `code_prefix` option of the
+/// enum is set, and the discriminants that are not integer literals (or follow one that is not) are left out. The function will
+/// panic if two variants have the same integer code.
+pub fn get_numbers<'a, V>(variants: V, enum_opts: &EnumOpts) -> Vec<Option<i64>>
+where
+    V: IntoIterator<Item = (&'a Variant, &'a VariantOpts)>,
+{
+    let parse = |expr: &Expr| match expr {
+        Expr::Lit(ExprLit { lit: Lit::Int(lit_int), .. }) => lit_int.base10_parse::<i64>().ok(),
+        _ => None,
+    };
+    let mut numbers = Vec::<(Option<i64>, &Ident)>::new();
+    let mut next_discriminant = Some(0_i64);
+
+    for (variant, opts) in variants {
+        // The discriminant of the variant, which is `None` if it cannot be computed at expansion time.
+        let discriminant = match &variant.discriminant {
+            Some((_, Expr::Unary(ExprUnary { op: UnOp::Neg(_), expr, .. }))) => parse(expr).and_then(i64::checked_neg),
+            Some((_, expr)) => parse(expr),
+            None => next_discriminant,
+        };
+        next_discriminant = discriminant.and_then(|d| d.checked_add(1));
+
+        let number = match (&opts.code, &variant.discriminant, &enum_opts.code_prefix) {
+            (Some(VariantCode::Number(number)), _, _) => i64::try_from(*number).ok(),
+            (_, Some(_), _) | (_, None, Some(_)) => discriminant,
+            (_, None, None) => None,
+        };
+
+        if let Some((_, other)) = numbers.iter().find(|(other_number, _)| number.is_some() && *other_number == number) {
+            panic!("{}", Error::DuplicateCode(&number.unwrap().to_string(), other, &variant.ident));
+        }
+
+        numbers.push((number, &variant.ident));
+    }
+
+    numbers.into_iter().map(|(number, _)| number).collect()
+}
+
+/// A tool that returns the code of each variant of an enum, or `None` for the variants that don't have one. Numeric codes are
+/// prefixed with the `code_prefix` option of the enum and padded with zeros up to its `code_width` option. If the `code_prefix`
+/// option is set, the variants that don't have a code are numbered after their integer codes (see [`get_numbers`]), so that
+/// the codes and the integer codes of the variants always agree. The function will panic in the following cases: if the
+/// integer code of a variant must be used but is not available, or if two variants have the same code.
+pub fn get_codes<'a, V>(variants: V, numbers: &[Option<i64>], enum_opts: &EnumOpts) -> Vec<Option<String>>
+where
+    V: IntoIterator<Item = (&'a Variant, &'a VariantOpts)>,
+{
+    let prefix = enum_opts.code_prefix.clone().unwrap_or_default();
+    let mut codes = Vec::<(Option<String>, &Ident)>::new();
+
+    for ((variant, opts), number) in variants.into_iter().zip(numbers) {
+        let code = match (&opts.code, &enum_opts.code_prefix, number) {
+            (Some(VariantCode::Text(code)), _, _) => Some(code.clone()),
+            (Some(VariantCode::Number(number)), _, _) => {
+                Some(format!("{}{:0width$}", prefix, number, width = enum_opts.code_width))
+            }
+            (None, Some(_), Some(number)) => Some(format!("{}{:0width$}", prefix, number, width = enum_opts.code_width)),
+            (None, Some(_), None) => panic!("{}", Error::UnsupportedDiscriminant(&variant.ident)),
+            (None, None, _) => None,
+        };
+
+        if let Some((_, other)) = codes.iter().find(|(other_code, _)| code.is_some() && *other_code == code) {
+            panic!("{}", Error::DuplicateCode(code.as_ref().unwrap(), other, &variant.ident));
+        }
+
+        codes.push((code, &variant.ident));
+    }
+
+    codes.into_iter().map(|(code, _)| code).collect()
+}
+
+/// A tool that returns the options set through the `mabe` attribute on a field of a variant. The function will panic in the
+/// following cases: if the attribute cannot be parsed, if an option is not supported, if an option is used more than once, or if
+/// the replacement text of the `redact` option (or the text of the `label` option) is not a string literal.
+pub fn get_field_opts(field: &Field, variant: &Variant) -> FieldOpts {
+    let mut opts = FieldOpts::default();
+
+    for (opt, value) in get_opts(&field.attrs, &variant.ident) {
+        match (opt.as_str(), value) {
+            ("redact", None) => opts.redact = Some("***".to_string()),
+            ("redact", Some(Lit::Str(lit_str))) => opts.redact = Some(lit_str.value()),
+            ("interpolate", None) => opts.interpolate = true,
+            ("skip", None) => opts.skip = true,
+            ("source_code", None) => opts.source_code = true,
+            ("label", None) => opts.label = Some(String::new()),
+            ("label", Some(Lit::Str(lit_str))) => opts.label = Some(lit_str.value()),
+            ("source", None) => opts.source = true,
+            ("backtrace", None) => opts.backtrace = true,
+            ("location", None) => opts.location = true,
+            ("from", None) => (opts.from, opts.source) = (true, true),
+            (
+                "redact" | "interpolate" | "skip" | "source_code" | "label" | "source" | "backtrace" | "location" | "from",
+                Some(_),
+            ) => {
+                panic!("{}", Error::UnsupportedOptArg(&opt, &variant.ident))
+            }
+            _ => panic!("{}", Error::UnsupportedOpt(&opt, &variant.ident)),
+        }
+    }
+
+    opts
+}
+
+/// A tool that returns a tuple containing the formatted message and the extracted arguments as `String` and `Vec<String>` types
+/// respectively. In the formatted message, all single curly braces (i.e. curly braces that are not part of a placeholder), and
+/// all placeholders are replaced with generic placeholders (e.g. `{placeholder0}`, `{placeholder1}`, etc.). The replaced
+/// elements are stored in the extracted arguments vector. If a placeholder refers to one of the `fields` and carries a format
+/// spec (e.g. `{0:?}` or `{msg:>8}`), the spec is kept on the generic placeholder and only the field is extracted.
+pub fn format_msg<M: ToString, F: ToString>(msg: M, fields: &[F]) -> (String, Vec<String>) {
+    let msg = msg.to_string().trim().to_string();
+    let mut formatted_msg = String::new();
+    let mut extracted_args = Vec::<String>::new();
+    let mut placeholder_position = 0;
+    let mut checkpoint = 0;
+
+    while checkpoint < msg.len() {
+        let index_left_brace = find_active_left_brace(&msg, checkpoint);
+        let index_right_brace = find_active_right_brace(&msg, checkpoint);
+        let generic_placeholder = format!("{{placeholder{}}}", placeholder_position);
+
+        match (index_left_brace, index_right_brace) {
+            (Some(left), Some(right)) if left < right => {
+                let arg = &msg[left + 1..right];
+                formatted_msg.push_str(&msg[checkpoint..left]);
+
+                match arg.split_once(':') {
+                    Some((field, spec)) if find_target(field, fields).is_some() => {
+                        formatted_msg.push_str(&format!("{{placeholder{}:{}}}", placeholder_position, spec));
+                        extracted_args.push(field.to_string());
+                    }
+                    _ => {
+                        formatted_msg.push_str(generic_placeholder.as_str());
+                        extracted_args.push(arg.to_string());
+                    }
+                }
+
+                placeholder_position += 1;
+                checkpoint = right + 1;
+            }
+            (Some(left), None) => {
+                formatted_msg.push_str(&msg[checkpoint..left]);
+                formatted_msg.push_str(generic_placeholder.as_str());
+                extracted_args.push("{".to_string());
+                placeholder_position += 1;
+                checkpoint = left + 1;
+            }
+            (_, Some(right)) => {
+                formatted_msg.push_str(&msg[checkpoint..right]);
+                formatted_msg.push_str(generic_placeholder.as_str());
+                extracted_args.push("}".to_string());
+                placeholder_position += 1;
+                checkpoint = right + 1;
+            }
+            (None, None) => {
+                formatted_msg.push_str(&msg[checkpoint..]);
+                checkpoint = msg.len();
+            }
+        }
+    }
+
+    (formatted_msg, extracted_args)
+}
+
+/// A tool that returns the pattern bindings of the fields of an enum variant. The `dunder` parameter is a boolean that
+/// determines whether the pattern bindings should have underscores at the beginning and end (e.g. `_0_` for the fields of a
+/// tuple variant). The raw identifiers (e.g. `r#type`) are bound to a `__mabe_`-prefixed name instead, since they cannot be
+/// captured by a format string.
+pub fn get_bindings<F: ToString>(fields: &[F], dunder: bool) -> Vec<Ident> {
+    fields
+        .iter()
+        .map(|f| match (dunder, f.to_string()) {
+            (true, f) => Ident::new(format!("_{}_", f).as_str(), Span::call_site()),
+            (false, f) => match f.strip_prefix("r#") {
+                Some(raw) => Ident::new(format!("__mabe_{}", raw).as_str(), Span::call_site()),
+                None => Ident::new(f.as_str(), Span::call_site()),
+            },
+        })
+        .collect()
+}
+
+/// A tool that inlines the extracted arguments into a message formatted by [`format_msg`], so that the message can be passed
+/// to `format_args!` on its own. The placeholders that refer to one of the `fields` become inline captures of their pattern
+/// bindings (e.g. `{_0_:?}`), or the escaped replacement text of the field if it is redacted (without the format spec, so that
+/// the text is not quoted by `{0:?}`). The other placeholders become their escaped argument. The `redactions` slice holds the
+/// replacement text of each field of the variant (or `None` if the field is not redacted), in the same order as the `fields`
+/// slice.
+pub fn inline_args<M: ToString, A: ToString, F: ToString>(
+    msg: M,
+    args: &[A],
+    fields: &[F],
+    bindings: &[Ident],
+    redactions: &[Option<String>],
+) -> String {
+    let msg = msg.to_string();
+    let mut inlined_msg = String::new();
+    let mut chars = msg.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            // Keeps the escaped curly braces (i.e. `{{` and `}}`) escaped.
+            ('{', Some('{')) | ('}', Some('}')) => {
+                chars.next();
+                inlined_msg.push(c);
+                inlined_msg.push(c);
+            }
+            ('{', _) => {
+                let placeholder = chars.by_ref().take_while(|c| *c != '}').collect::<String>();
+                let (placeholder, spec) = match placeholder.split_once(':') {
+                    Some((placeholder, spec)) => (placeholder.to_string(), format!(":{}", spec)),
+                    None => (placeholder, String::new()),
+                };
+                let arg = args[placeholder.trim_start_matches("placeholder").parse::<usize>().unwrap()].to_string();
+
+                match find_target(&arg, fields) {
+                    Some(index) => match &redactions[index] {
+                        Some(text) => inlined_msg.push_str(&escape_msg(text)),
+                        None => inlined_msg.push_str(&format!("{{{}{}}}", bindings[index], spec)),
+                    },
+                    None => inlined_msg.push_str(&escape_msg(arg)),
+                }
+            }
+            _ => inlined_msg.push(c),
+        }
+    }
+
+    inlined_msg
+}
+
+/// A tool that returns the rendered message if none of the extracted arguments refers to one of the `fields`, and `None`
+/// otherwise. The message must have been formatted by [`format_msg`], so that the generic placeholders can be replaced with the
+/// extracted arguments and the escaped curly braces (i.e. `{{` and `}}`) can be unescaped.
+pub fn render_static_msg<M: ToString, A: ToString, F: ToString>(msg: M, args: &[A], fields: &[F]) -> Option<String> {
+    let msg = msg.to_string();
+    let args = args.iter().map(|a| a.to_string()).collect::<Vec<String>>();
+
+    if args.iter().any(|arg| find_target(arg, fields).is_some()) {
+        return None;
+    }
+
+    let mut rendered_msg = String::new();
+    let mut chars = msg.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            // Unescapes the escaped curly braces (i.e. `{{` and `}}`).
+            ('{', Some('{')) | ('}', Some('}')) => {
+                chars.next();
+                rendered_msg.push(c);
+            }
+            ('{', _) => {
+                let placeholder = chars.by_ref().take_while(|c| *c != '}').collect::<String>();
+                let index = placeholder.trim_start_matches("placeholder").parse::<usize>().unwrap();
+                rendered_msg.push_str(&args[index]);
+            }
+            _ => rendered_msg.push(c),
+        }
+    }
+
+    Some(rendered_msg)
+}
+
+/// A tool that returns the extracted arguments that refer to one of the `fields` (i.e. the names of the fields interpolated in a
+/// message), without duplicates and in the order of their first appearance in the message.
+pub fn get_placeholders<A: ToString, F: ToString>(args: &[A], fields: &[F]) -> Vec<String> {
+    let mut placeholders = Vec::<String>::new();
+
+    for arg in args.iter().map(|a| a.to_string()) {
+        if find_target(&arg, fields).is_some() && find_target(&arg, &placeholders).is_none() {
+            placeholders.push(arg);
+        }
+    }
+
+    placeholders
+}
+
+/// A tool that renders the template of a help URL, in which the `{code}` and `{variant}` placeholders are replaced with the code
+/// and the name of the variant respectively. `None` is returned if the template refers to the code of a variant that doesn't
+/// have one.
+pub fn render_url<T: ToString, V: ToString>(template: T, code: Option<&str>, variant: V) -> Option<String> {
+    let template = template.to_string();
+
+    match (template.contains("{code}"), code) {
+        (true, None) => None,
+        (_, code) => Some(template.replace("{code}", code.unwrap_or_default()).replace("{variant}", &variant.to_string())),
+    }
+}
+
+/// A tool that converts the name of a variant into snake case (e.g. `ConnectionLost` becomes `connection_lost`, and
+/// `HTTPTimeout` becomes `http_timeout`).
+pub fn to_snake_case<N: ToString>(name: N) -> String {
+    let chars = name.to_string().chars().collect::<Vec<char>>();
+    let mut snake_case = String::new();
+
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let after_lowercase = chars[i - 1].is_lowercase() || chars[i - 1].is_ascii_digit();
+            let before_lowercase = chars[i - 1].is_uppercase() && chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+
+            if (after_lowercase || before_lowercase) && chars[i - 1] != '_' {
+                snake_case.push('_');
+            }
+        }
+
+        snake_case.extend(c.to_lowercase());
+    }
+
+    snake_case
+}
+
+/// A tool that returns the names of the methods generated for an enum, which cannot be used by the constructors and the
+/// accessors of its variants. The methods that are only generated with some options of the enum or some fields of its variants
+/// are only included if they are generated (e.g. `kind` with the `kind` option, and `backtrace` if a variant has a backtrace).
+pub fn get_generated_methods(enum_opts: &EnumOpts, backtrace: bool, location: bool) -> Vec<&'static str> {
+    let mut methods = vec![
+        "variant_info",
+        "variant_name",
+        "code",
+        "severity",
+        "explain",
+        "help_url",
+        "explain_code",
+        "error_str",
+        "debug_str",
+        "write_error",
+        "write_debug",
+    ];
+
+    if !enum_opts.no_alloc {
+        methods.extend(["state", "error", "debug"]);
+    }
+
+    if enum_opts.kind {
+        methods.push("kind");
+    }
+
+    if backtrace {
+        methods.push("backtrace");
+    }
+
+    if location {
+        methods.push("location");
+    }
+
+    if enum_opts.try_from_code {
+        methods.push("as_code");
+    }
+
+    methods
+}
+
+/// A tool that returns the identifier of the constructor of a variant (i.e. the name of the variant in snake case, which is
+/// a raw identifier if it is a keyword).
+pub fn get_constructor_ident(variant: &Variant) -> Ident {
+    let name = to_snake_case(&variant.ident);
+
+    match syn::parse_str::<Ident>(&name) {
+        Ok(_) => Ident::new(&name, variant.ident.span()),
+        Err(_) => Ident::new_raw(&name, variant.ident.span()),
+    }
+}
+
+/// A tool that returns `true` if a type is an owned string-like type (i.e. `String`, `Box<str>`, `Cow<str>`, `PathBuf`, or
+/// `OsString`), whose values are taken as `impl Into<T>` by the constructors of the variants.
+pub fn is_string_like(ty: &Type) -> bool {
+    let segment = match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => match type_path.path.segments.last() {
+            Some(segment) => segment,
+            None => return false,
+        },
+        _ => return false,
+    };
+
+    // The first type argument of the segment (e.g. `str` in `Box<str>`).
+    let is_str_arg = || match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| match arg {
+            GenericArgument::Type(Type::Path(arg)) => arg.path.is_ident("str"),
+            _ => false,
+        }),
+        _ => false,
+    };
+
+    match segment.ident.to_string().as_str() {
+        "String" | "PathBuf" | "OsString" => segment.arguments.is_empty(),
+        "Box" | "Cow" => is_str_arg(),
+        _ => false,
+    }
+}
+
+/// A tool that escapes the curly braces of a message (i.e. `{` and `}` become `{{` and `}}` respectively), so that it can be
+/// used as a format string.
+pub fn escape_msg<M: ToString>(msg: M) -> String {
+    msg.to_string().replace('{', "{{").replace('}', "}}")
+}
+
+/// A tool that returns a styled prefix for the `error` and `debug` attributes (as well as for the labels of the other
+/// severities, for the `see` and `at` lines, and for the `backtrace` section) using ANSI escape codes. The `colorize`
+/// Cargo feature must be enabled for this function to work.
+pub fn style_prefix<A: ToString>(attribute: A) -> String {
+    let attribute = attribute.to_string();
+
+    if !["note", "warning", "error", "fatal", "debug", "see", "at", "backtrace"].contains(&attribute.as_str()) {
+        panic!("{}", Error::InvalidAttr(&attribute, "helpers::style_prefix"));
+    }
+
+    #[cfg(feature = "colorize")]
+    match attribute.as_str() {
+        "note" => return "\u{1b}[1;36m[note]\u{1b}[0m".to_string(), // ANSI escape code for cyan and bold text.
+        "warning" => return "\u{1b}[1;33m[warning]\u{1b}[0m".to_string(), // ANSI escape code for yellow and bold text.
+        "error" => return "\u{1b}[1;31m[error]\u{1b}[0m".to_string(), // ANSI escape code for red and bold text.
+        "fatal" => return "\u{1b}[1;35m[fatal]\u{1b}[0m".to_string(), // ANSI escape code for magenta and bold text.
+        "debug" => return "\u{1b}[1;32m[debug]\u{1b}[0m".to_string(), // ANSI escape code for green and bold text.
+        "see" => return "\u{1b}[1;34m[see]\u{1b}[0m".to_string(),   // ANSI escape code for blue and bold text.
+        "at" => return "\u{1b}[1;90m[at]\u{1b}[0m".to_string(),     // ANSI escape code for gray and bold text.
+        "backtrace" => return "\u{1b}[1;37m[backtrace]\u{1b}[0m".to_string(), // ANSI escape code for white and bold text.
+        _ => return String::new(),                                  // This should never be reached.
+    };
+
+    #[cfg(not(feature = "colorize"))]
+    return format!("[{}]", attribute);
+}
+
+/// Returns the string representation of a path (e.g. `redact` or `core::fmt`).
+fn path_to_string(path: &Path) -> String {
+    path.segments.iter().map(|segment| segment.ident.to_string()).collect::<Vec<String>>().join("::")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    #[test]
+    fn test_format_msg() {
+        // Example 1: Even number of curly braces.
+        let msg1 = "The placeholders are: {x}, {{y}} and {z}.".to_string();
+        let (formatted_msg1, extracted_args_msg1) = format_msg(msg1, &Vec::<String>::new());
+        assert_eq!(formatted_msg1, "The placeholders are: {placeholder0}, {{y}} and {placeholder1}.");
+        assert_eq!(extracted_args_msg1, vec!["x".to_string(), "z".to_string()]);
+
+        // Example 2: Uneven number of curly braces.
+        let msg2 = "The placeholders are: {x}, {{{y}}} and {z}}}.".to_string();
+        let (formatted_msg2, extracted_args_msg2) = format_msg(msg2, &Vec::<String>::new());
+        assert_eq!(formatted_msg2, "The placeholders are: {placeholder0}, {{{placeholder1}}} and {placeholder2}}}.");
+        assert_eq!(extracted_args_msg2, vec!["x".to_string(), "y".to_string(), "z".to_string()]);
+
+        // Example 3: Even number of curly braces.
+        let msg3 = "The placeholders are: {{x}}, {{{{y}}}} and {{{{z}}.".to_string();
+        let (formatted_msg3, extracted_args_msg3) = format_msg(msg3, &Vec::<String>::new());
+        assert_eq!(formatted_msg3, "The placeholders are: {{x}}, {{{{y}}}} and {{{{z}}.");
+        assert_eq!(extracted_args_msg3, Vec::<String>::new());
+
+        // Example 4: Empty curly braces.
+        let msg4 = "The placeholders are: {}, {{}}}} and {{{ }.".to_string();
+        let (formatted_msg4, extracted_args_msg4) = format_msg(msg4, &Vec::<String>::new());
+        assert_eq!(formatted_msg4, "The placeholders are: {placeholder0}, {{}}}} and {{{placeholder1}.");
+        assert_eq!(extracted_args_msg4, vec!["".to_string(), " ".to_string()]);
+
+        // Example 5: Unbalanced curly braces.
+        let msg5 = "The placeholders are: {x}}, {y} and z.".to_string();
+        let (formatted_msg5, extracted_args_msg5) = format_msg(msg5, &Vec::<String>::new());
+        assert_eq!(formatted_msg5, "The placeholders are: {placeholder0} and z.");
+        assert_eq!(extracted_args_msg5, vec!["x}}, {y".to_string()]);
+
+        // Example 6: Format specs on field placeholders.
+        let msg6 = "The placeholders are: {x:?}, {y:>4}, {z:?} and {{x:?}}.".to_string();
+        let fields6 = vec!["x".to_string(), "y".to_string()];
+        let (formatted_msg6, extracted_args_msg6) = format_msg(msg6, &fields6);
+        assert_eq!(formatted_msg6, "The placeholders are: {placeholder0:?}, {placeholder1:>4}, {placeholder2} and {{x:?}}.");
+        assert_eq!(extracted_args_msg6, vec!["x".to_string(), "y".to_string(), "z:?".to_string()]);
+    }
+
+    #[test]
+    fn test_get_msg() {
+        // Example 1: Doc comments and other attributes are ignored.
+        let variant1: Variant = parse_quote! {
+            /// The doc comment of the variant.
+            #[doc = "Another doc comment."]
+            #[allow(dead_code)]
+            #[error("The error message.")]
+            #[debug("The debug message.")]
+            A
+        };
+        assert_eq!(get_msg("error", &variant1), "The error message.");
+        assert_eq!(get_msg("debug", &variant1), "The debug message.");
+
+        // Example 2: The debug message is optional.
+        let variant2: Variant = parse_quote! {
+            /// The doc comment of the variant, which is not a debug message.
+            #[error("The error message.")]
+            B
+        };
+        assert_eq!(get_msg("debug", &variant2), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "The `C` variant is missing the `error` attribute.")]
+    fn test_get_msg_doc_only() {
+        get_msg(
+            "error",
+            &parse_quote! {
+                /// The doc comment of the variant, which is not an error message.
+                C
+            },
+        );
+    }
+
+    #[test]
+    fn test_get_bindings() {
+        // Example 1: Empty `fields` vector.
+        assert!(get_bindings(&Vec::<String>::new(), true).is_empty());
+
+        // Example 2: The fields of a tuple variant.
+        let bindings2 = get_bindings(&["0", "1"], true);
+        assert_eq!(quote! {[#(#bindings2),*]}.to_string(), "[_0_ , _1_]");
+
+        // Example 3: The fields of a struct variant, including a raw identifier.
+        let bindings3 = get_bindings(&["x", "r#type"], false);
+        assert_eq!(quote! {[#(#bindings3),*]}.to_string(), "[x , __mabe_type]");
+    }
+
+    #[test]
+    fn test_inline_args() {
+        // Example 1: No redacted fields, and placeholders that don't refer to any field.
+        let fields1 = vec!["x".to_string(), "y".to_string()];
+        let bindings1 = get_bindings(&fields1, false);
+        let (msg1, args1) = format_msg("{x:?} and {y:>4}, {z}, {{w}} and {", &fields1);
+        assert_eq!(inline_args(msg1, &args1, &fields1, &bindings1, &[None, None]), "{x:?} and {y:>4}, z, {{w}} and {{");
+
+        // Example 2: Redacted fields with and without format specs, including one whose replacement text is the name of another
+        // field.
+        let fields2 = vec!["x".to_string(), "y".to_string(), "w".to_string()];
+        let bindings2 = get_bindings(&fields2, true);
+        let (msg2, args2) = format_msg("{x:?}, {y}, {z} and {x:>8}, {w:?}", &fields2);
+        let redactions2 = [Some("{***}".to_string()), Some("x".to_string()), None];
+        assert_eq!(inline_args(msg2, &args2, &fields2, &bindings2, &redactions2), "{{***}}, x, z and {{***}}, {_w_:?}");
+    }
+
+    #[test]
+    fn test_render_static_msg() {
+        // Example 1: No placeholders.
+        let fields1 = Vec::<String>::new();
+        let (msg1, args1) = format_msg("The message is: {{x}}, {{{{y}}}} and z.", &fields1);
+        assert_eq!(render_static_msg(msg1, &args1, &fields1), Some("The message is: {x}, {{y}} and z.".to_string()));
+
+        // Example 2: Placeholders that don't refer to any field.
+        let fields2 = vec!["x".to_string()];
+        let (msg2, args2) = format_msg("The message is: {y}, {{{z}}}, {} and {x:?.", &fields2);
+        assert_eq!(render_static_msg(msg2, &args2, &fields2), Some("The message is: y, {z},  and {x:?.".to_string()));
+
+        // Example 3: Placeholders that refer to a field.
+        let fields3 = vec!["x".to_string()];
+        let (msg3, args3) = format_msg("The message is: {y} and {x}.", &fields3);
+        assert_eq!(render_static_msg(msg3, &args3, &fields3), None);
+    }
+
+    #[test]
+    fn test_get_codes() {
+        let codes = |input: DeriveInput| match &input.data {
+            syn::Data::Enum(enum_data) => {
+                let (opts, enum_opts) =
+                    (enum_data.variants.iter().map(get_variant_opts).collect::<Vec<_>>(), get_enum_opts(&input));
+                let numbers = get_numbers(enum_data.variants.iter().zip(&opts), &enum_opts);
+                get_codes(enum_data.variants.iter().zip(&opts), &numbers, &enum_opts)
+            }
+            _ => unreachable!(),
+        };
+
+        // Example 1: Codes set on some of the variants.
+        let input1 = parse_quote! { enum E { #[mabe(code = "AUTH-X")] A, B, #[mabe(code = 7)] C } };
+        assert_eq!(codes(input1), vec![Some("AUTH-X".to_string()), None, Some("0007".to_string())]);
+
+        // Example 2: Codes numbered after the discriminants of the variants.
+        let input2 = parse_quote! {
+            #[mabe(code_prefix = "DB-", code_width = 3)]
+            enum E { A, B = 10, C, #[mabe(code = "DB-XYZ")] D, #[mabe(code = 42)] E }
+        };
+        let expected2 = vec!["DB-000", "DB-010", "DB-011", "DB-XYZ", "DB-042"];
+        assert_eq!(codes(input2), expected2.into_iter().map(|c| Some(c.to_string())).collect::<Vec<Option<String>>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "The `E` enum has the `try_from_code` option, but the conversions are not available")]
+    fn test_get_enum_opts_conversion_without_alloc() {
+        get_enum_opts(&parse_quote! { #[mabe(no_alloc, try_from_code)] enum E { A = 1 } });
+    }
+
+    #[test]
+    #[should_panic(expected = "the `B` variant has the same code as the `A` variant: `E-0001`.")]
+    fn test_get_codes_duplicate() {
+        let input: DeriveInput = parse_quote! { #[mabe(code_prefix = "E-")] enum E { A = 1, #[mabe(code = "E-0001")] B } };
+        if let syn::Data::Enum(enum_data) = &input.data {
+            let (opts, enum_opts) =
+                (enum_data.variants.iter().map(get_variant_opts).collect::<Vec<_>>(), get_enum_opts(&input));
+            let numbers = get_numbers(enum_data.variants.iter().zip(&opts), &enum_opts);
+            get_codes(enum_data.variants.iter().zip(&opts), &numbers, &enum_opts);
+        }
+    }
+
+    #[test]
+    fn test_get_numbers() {
+        let input: DeriveInput = parse_quote! {
+            enum Enum {
+                A = 3,
+                B = -2,
+                #[mabe(code = 42)]
+                C,
+                #[mabe(code = "E-0001")]
+                D,
+                E,
+                F = N,
+            }
+        };
+        if let syn::Data::Enum(enum_data) = &input.data {
+            let opts = enum_data.variants.iter().map(get_variant_opts).collect::<Vec<_>>();
+            let numbers = get_numbers(enum_data.variants.iter().zip(&opts), &get_enum_opts(&input));
+            assert_eq!(numbers, vec![Some(3), Some(-2), Some(42), None, None, None]);
+        }
+
+        // The implicit discriminants are used if the `code_prefix` option is set, and the numeric codes take precedence over the
+        // discriminants.
+        let input2: DeriveInput = parse_quote! {
+            #[mabe(code_prefix = "DB-")]
+            enum Enum {
+                A = 10,
+                B,
+                #[mabe(code = 42)]
+                C = 3,
+                D,
+            }
+        };
+        if let syn::Data::Enum(enum_data) = &input2.data {
+            let opts = enum_data.variants.iter().map(get_variant_opts).collect::<Vec<_>>();
+            let numbers = get_numbers(enum_data.variants.iter().zip(&opts), &get_enum_opts(&input2));
+            assert_eq!(numbers, vec![Some(10), Some(11), Some(42), Some(4)]);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the `C` variant has the same code as the `A` variant: `42`.")]
+    fn test_get_numbers_duplicate() {
+        let input: DeriveInput = parse_quote! { enum E { A = 42, B, #[mabe(code = 42)] C } };
+        if let syn::Data::Enum(enum_data) = &input.data {
+            let opts = enum_data.variants.iter().map(get_variant_opts).collect::<Vec<_>>();
+            get_numbers(enum_data.variants.iter().zip(&opts), &get_enum_opts(&input));
+        }
+    }
+
+    #[test]
+    fn test_render_url() {
+        // Example 1: Template with both placeholders.
+        let url1 = render_url("https://docs.example.com/{variant}/{code}", Some("E0042"), "Timeout");
+        assert_eq!(url1, Some("https://docs.example.com/Timeout/E0042".to_string()));
+
+        // Example 2: Template that refers to the code of a variant that doesn't have one.
+        assert_eq!(render_url("https://docs.example.com/{code}", None, "Timeout"), None);
+
+        // Example 3: Template without placeholders.
+        let url3 = render_url("https://docs.example.com/errors", None, "Timeout");
+        assert_eq!(url3, Some("https://docs.example.com/errors".to_string()));
+    }
+
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case("ConnectionLost"), "connection_lost");
+        assert_eq!(to_snake_case("HTTPTimeout"), "http_timeout");
+        assert_eq!(to_snake_case("Error404NotFound"), "error404_not_found");
+        assert_eq!(to_snake_case("Io"), "io");
+        assert_eq!(to_snake_case("Type"), "type");
+    }
+
+    #[test]
+    fn test_get_constructor_ident() {
+        let variant1: Variant = parse_quote! { ConnectionLost { cause: String } };
+        assert_eq!(get_constructor_ident(&variant1).to_string(), "connection_lost");
+
+        // The names that are keywords are raw identifiers.
+        let variant2: Variant = parse_quote! { Type(String) };
+        assert_eq!(get_constructor_ident(&variant2).to_string(), "r#type");
+    }
+
+    #[test]
+    fn test_get_generated_methods() {
+        // Example 1: The methods of the default options.
+        let methods1 = get_generated_methods(&EnumOpts::default(), false, false);
+        assert!(methods1.contains(&"error") && methods1.contains(&"variant_info"));
+        assert!(!methods1.contains(&"kind") && !methods1.contains(&"backtrace") && !methods1.contains(&"location"));
+
+        // Example 2: The methods that depend on the options of the enum and on the fields of its variants.
+        let opts2 = EnumOpts { no_std: true, no_alloc: true, kind: true, try_from_code: true, ..Default::default() };
+        let methods2 = get_generated_methods(&opts2, true, true);
+        assert!(!methods2.contains(&"error") && !methods2.contains(&"state"));
+        assert!(methods2.contains(&"kind") && methods2.contains(&"backtrace") && methods2.contains(&"location"));
+        assert!(methods2.contains(&"as_code"));
+    }
+
+    #[test]
+    fn test_is_string_like() {
+        assert!(is_string_like(&parse_quote! { String }));
+        assert!(is_string_like(&parse_quote! { std::string::String }));
+        assert!(is_string_like(&parse_quote! { Box<str> }));
+        assert!(is_string_like(&parse_quote! { Cow<'static, str> }));
+        assert!(is_string_like(&parse_quote! { PathBuf }));
+        assert!(!is_string_like(&parse_quote! { &'static str }));
+        assert!(!is_string_like(&parse_quote! { Box<dyn Error> }));
+        assert!(!is_string_like(&parse_quote! { Vec<String> }));
+        assert!(!is_string_like(&parse_quote! { u32 }));
+    }
+
+    #[test]
+    fn test_get_placeholders() {
+        // Example 1: Placeholders that refer to the fields, with duplicates and format specs.
+        let fields1 = vec!["0".to_string(), "1".to_string()];
+        let (_, args1) = format_msg("The values are: {1}, {0:?}, {1:>8} and {{0}}.", &fields1);
+        assert_eq!(get_placeholders(&args1, &fields1), vec!["1".to_string(), "0".to_string()]);
+
+        // Example 2: Placeholders that don't refer to any field.
+        let fields2 = vec!["x".to_string()];
+        let (_, args2) = format_msg("The message is: {y}, {{x}} and {}.", &fields2);
+        assert_eq!(get_placeholders(&args2, &fields2), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use syn::Ident;
+
+/// The custom error type for the `api` module.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error<'a> {
+    AttrParsingFailed(&'a Ident),
+    BacktraceWithoutStd(&'a Ident),
+    ConversionWithoutAlloc(&'a String, &'a Ident),
+    DuplicateCode(&'a String, &'a Ident, &'a Ident),
+    EmptyAttr(&'a String, &'a Ident),
+    EmptyEnum,
+    ErrAttrNotFound(&'a Ident),
+    ExcessAttr(&'a String, &'a Ident),
+    ExcessOpt(&'a String, &'a Ident),
+    IdentRetrievalFailed(&'a Ident),
+    InterpolatedRedactedField(&'a Ident, &'a String),
+    InvalidAttr(&'a String, &'a str),
+    InvalidOptValue(&'a String, &'a str, &'a Ident),
+    MethodNameClash(&'a String, &'a Ident, &'a String),
+    MissingSourceCode(&'a Ident),
+    NotAnEnum,
+    UnexpectedAttrArgs(&'a String, usize, &'a Ident),
+    UnfillableField(&'a String, &'a Ident),
+    UnsupportedAttrArg(&'a String, &'a Ident),
+    UnsupportedDiscriminant(&'a Ident),
+    UnsupportedFromVariant(&'a Ident),
+    UnsupportedOpt(&'a String, &'a Ident),
+    UnsupportedOptArg(&'a String, &'a Ident),
+    UnusedVariantField(&'a Ident, &'a String),
+}
+
+impl<'a> std::fmt::Display for Error<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AttrParsingFailed(var_ident) => write!(f, "[error] Failed to parse the attributes of the `{}` variant.\n[debug] This error should not be possible, try reloading the window. If the problem persists, report the issue to the crate's [GitHub repository](https://github.com/AmonRayfa/mabe).", var_ident),
+            Self::BacktraceWithoutStd(var_ident) => write!(f, "[error] The `{}` variant has a field with the `backtrace` option, but backtraces are not available in `no_std` environments.\n[debug] Remove the `backtrace` option, or the `no_std` option of the enum.", var_ident),
+            Self::ConversionWithoutAlloc(opt, enum_ident) => write!(f, "[error] The `{}` enum has the `{}` option, but the conversions are not available with the `no_alloc` option, since they fail with a `ConversionError` (which holds the input as a `String`).\n[debug] Remove the `{}` option, or the `no_alloc` option of the enum.", enum_ident, opt, opt),
+            Self::DuplicateCode(code, var_ident1, var_ident2) => write!(f, "[error] The codes of the variants must be unique, but the `{}` variant has the same code as the `{}` variant: `{}`.", var_ident2, var_ident1, code),
+            Self::EmptyAttr(attr, var_ident) => write!(f, "[error] `Mabe` attributes cannot be empty, but the `{}` attribute of the `{}` variant is.", attr, var_ident),
+            Self::EmptyEnum => write!(f, "[error] The `Mabe` derive macro cannot be used on empty enums."),
+            Self::ErrAttrNotFound(var_ident) => write!(f, "[error] The `{}` variant is missing the `error` attribute.", var_ident),
+            Self::ExcessAttr(attr, var_ident)=> write!(f, "[error] `Mabe` attributes can only be used once on the same variant, but the `{}` variant has multiple `{}` attributes.", var_ident, attr),
+            Self::ExcessOpt(opt, ident) => write!(f, "[error] `mabe` options can only be used once on the same item, but the `{}` option was found multiple times on `{}`.", opt, ident),
+            Self::IdentRetrievalFailed(var_ident) => write!(f, "[error] Failed to retrieve the identifier of a field of the `{}` variant.\n[debug] This error should not be possible, try reloading the window. If the problem persists, report the issue to the crate's [GitHub repository](https://github.com/AmonRayfa/mabe).", var_ident),
+            Self::InterpolatedRedactedField(var_ident, field) => write!(f, "[error] The `{}` field of the `{}` variant is redacted, but it is interpolated in the error message.\n[debug] Remove the placeholder, or add the `interpolate` option to the `mabe` attribute of the field to render its replacement text instead.", field, var_ident),
+            Self::InvalidAttr(attr, func) => write!(f, "[error] The `api::derive_macro::{}` function doesn't support the `{}` attribute.\n[debug] This error should not be possible, try reloading the window. If the problem persists, report the issue to the crate's [GitHub repository](https://github.com/AmonRayfa/mabe).", func, attr),
+            Self::InvalidOptValue(opt, value, ident) => write!(f, "[error] The value `{}` is not valid for the `{}` option of the `mabe` attribute on `{}`.", value, opt, ident),
+            Self::MethodNameClash(name, var_ident, opt) => write!(f, "[error] The `{}` method of the `{}` variant cannot be generated, because its name is already used by another generated method.\n[debug] Rename the variant, or add the `{}` option to its `mabe` attribute.", name, var_ident, opt),
+            Self::MissingSourceCode(var_ident) => write!(f, "[error] The `{}` variant has labeled fields, but none of its fields holds the source code that the labels refer to.\n[debug] Add the `source_code` option to the `mabe` attribute of the field that holds the source code.", var_ident),
+            Self::NotAnEnum => write!(f, "[error] The `Mabe` derive macro can only be used on enums."),
+            Self::UnexpectedAttrArgs(attr, args_count, var_ident) => write!(f, "[error] `Mabe` attributes can only take 1 argument, but `{}` were found for the `{}` attribute of the `{}` variant.", args_count, attr, var_ident),
+            Self::UnfillableField(opt, var_ident) => write!(f, "[error] The `{}` variant has a field with the `{}` option, but it has neither a constructor nor a field with the `from` option, so nothing can fill the field.\n[debug] Remove the `no_constructor` option of the variant, or add the `from` option to its source.", var_ident, opt),
+            Self::UnsupportedAttrArg(attr, var_ident) => write!(f, "[error] The argument of a `Mabe` attribute must be a string literal, but a different type was found for the argument of the `{}` attribute of the `{}` variant.", attr, var_ident),
+            Self::UnsupportedDiscriminant(var_ident) => write!(f, "[error] The code of the `{}` variant cannot be numbered after its discriminant, which is not an integer literal (or follows one that is not).\n[debug] Set the code of the variant with the `code` option of its `mabe` attribute.", var_ident),
+            Self::UnsupportedFromVariant(var_ident) => write!(f, "[error] The `{}` variant has a field with the `from` option, but it has other fields that don't have the `backtrace` or `location` option.\n[debug] A `From` implementation can only be generated if the other fields of the variant can be created automatically.", var_ident),
+            Self::UnsupportedOpt(opt, ident) => write!(f, "[error] The `{}` option of the `mabe` attribute is not supported here, but it was found on `{}`.", opt, ident),
+            Self::UnsupportedOptArg(opt, ident) => write!(f, "[error] The argument of the `{}` option of the `mabe` attribute on `{}` is missing, unexpected, or not of the expected type.", opt, ident),
+            Self::UnusedVariantField(var_ident, field) => write!(f, "[error] The `{}` field of the `{}` variant is not used in the error or debug message.\n[debug] Interpolate the field, add the `skip` option to the `mabe` attribute of the field, or set the `unused_fields` option of the enum to `\"allow\"` or `\"warn\"`.", field, var_ident),
+        }
+    }
+}
+
+impl<'a> std::error::Error for Error<'a> {}
@@ -2,6 +2,4 @@
 // SPDX-License-Identifier: Apache-2.0.
 
 pub(crate) mod api;
-
-#[cfg(debug_assertions)]
 pub(crate) mod utils;
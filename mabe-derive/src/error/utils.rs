@@ -0,0 +1,21 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use std::path::Path;
+
+/// The custom error type for the `utils` module.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error<'a> {
+    ExpansionDumpFailed(&'a Path, std::io::Error),
+}
+
+impl<'a> std::fmt::Display for Error<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ExpansionDumpFailed(file, e) => write!(f, "[warning] Failed to write the output of the `Mabe` derive macro to `{}`: {}.\n[debug] Make sure that the directory set by the `MABE_EXPAND_DIR` environment variable is writable, or unset the variable.", file.display(), e),
+        }
+    }
+}
+
+impl<'a> std::error::Error for Error<'a> {}
@@ -0,0 +1,22 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+//! This crate provides the derive macro of [**Mabe**](https://github.com/AmonRayfa/mabe), a simple framework for creating
+//! debug-friendly error enums in Rust. It is not meant to be used directly: the macro is re-exported by the
+//! [`mabe`](https://docs.rs/mabe) crate, which also provides the runtime items that the generated code relies on.
+
+extern crate proc_macro;
+mod api;
+mod error;
+mod utils;
+use api::mabe;
+
+/// The derive macro that creates the debug-friendly error enums. It provides an `error` and a `debug` attribute for each
+/// variant of the enum, which can be used to define the error and debug messages respectively, an `explain` attribute for the
+/// long-form explanation of each variant, and a `mabe` attribute for the options of the enum, its variants, and their fields
+/// (e.g. `#[mabe(code = "AUTH-0003")]` or `#[mabe(redact)]`). The macro also automatically generates implementations for the
+/// [`Debug`](std::fmt::Debug), [`Display`](std::fmt::Display), and [`Error`](std::error::Error) traits.
+#[proc_macro_derive(Mabe, attributes(error, debug, explain, mabe))]
+pub fn mabe_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    mabe(input)
+}
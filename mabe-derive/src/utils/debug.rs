@@ -0,0 +1,113 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use crate::error::utils::Error;
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+use std::collections::hash_map::DefaultHasher;
+use std::env::{var, var_os};
+use std::fs::{create_dir_all, write};
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use syn::{Data, DeriveInput};
+
+/// The environment variable that holds the directory in which the outputs of the `Mabe` derive macro are written.
+pub const EXPAND_DIR_VAR: &str = "MABE_EXPAND_DIR";
+
+/// Writes the output of a macro (i.e. the generated code) for an enum to a file named `<crate>-<enum>-<hash>.rs` in the
+/// directory set by the `MABE_EXPAND_DIR` environment variable, and does nothing if the variable is not set. The hash is
+/// computed from the names of the variants of the enum (the module path of an enum is not available to the macro), so that the
+/// enums that have the same name in different modules get different files, while the file of an enum is overwritten every time
+/// the enum is expanded again (e.g. on every build, or by a language server). The files are formatted with `rustfmt` if it is
+/// available, and a failed write returns a warning to emit with the generated code, so that it never fails the build.
+pub fn dump_macro_output(macro_output: &TokenStream, input: &DeriveInput) -> Option<TokenStream> {
+    let dir = match var_os(EXPAND_DIR_VAR) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => return None,
+    };
+
+    let mut hasher = DefaultHasher::new();
+    if let Data::Enum(enum_data) = &input.data {
+        enum_data.variants.iter().for_each(|variant| variant.ident.to_string().hash(&mut hasher));
+    }
+
+    let crate_name = var("CARGO_CRATE_NAME").unwrap_or_else(|_| "unknown".to_string());
+    let file = dir.join(format!("{}-{}-{:08x}.rs", crate_name, input.ident, hasher.finish() as u32));
+    let content = format!(
+        "// The implementations generated by the `Mabe` derive macro for the `{}::{}` enum.\n\n{}\n",
+        crate_name, input.ident, macro_output
+    );
+
+    // Stable Rust doesn't let procedural macros emit warnings, so the warning is emitted by using a deprecated constant whose
+    // note is the warning message (like the warnings of the unused fields).
+    if let Err(e) = create_dir_all(&dir).and_then(|_| write(&file, content)) {
+        let note = Error::ExpansionDumpFailed(&file, e).to_string();
+        return Some(quote_spanned! { input.ident.span() =>
+            const _: () = {
+                #[deprecated(note = #note)]
+                #[allow(non_upper_case_globals)]
+                const mabe_expand_dir: () = ();
+                mabe_expand_dir
+            };
+        });
+    }
+
+    // Formatting is a best-effort step, so the raw output is kept if `rustfmt` is not available or fails.
+    let _ = Command::new(var("RUSTFMT").unwrap_or_else(|_| "rustfmt".to_string()))
+        .args(["--edition", "2021"])
+        .arg(&file)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+    use std::env::{remove_var, set_var, temp_dir};
+    use std::fs::{read_dir, read_to_string, remove_dir_all};
+    use syn::parse_quote;
+
+    #[test]
+    fn test_dump_macro_output() {
+        let dir = temp_dir().join(format!("mabe-expand-{}", std::process::id()));
+        let server_error: DeriveInput = parse_quote! { enum ServerError { Timeout, Overloaded } };
+        let other_server_error: DeriveInput = parse_quote! { enum ServerError { NotFound } };
+        let files = || read_dir(&dir).map(|entries| entries.count()).unwrap_or(0);
+
+        // Example 1: The environment variable is not set.
+        remove_var(EXPAND_DIR_VAR);
+        assert!(dump_macro_output(&quote! { impl ServerError {} }, &server_error).is_none());
+        assert_eq!(files(), 0);
+
+        // Example 2: The same enum is written to the same file every time it is expanded.
+        set_var(EXPAND_DIR_VAR, &dir);
+        assert!(dump_macro_output(&quote! { impl ServerError { fn first() {} } }, &server_error).is_none());
+        assert!(dump_macro_output(&quote! { impl ServerError { fn second() {} } }, &server_error).is_none());
+        assert_eq!(files(), 1);
+
+        let file = read_dir(&dir).unwrap().next().unwrap().unwrap().path();
+        let name = file.file_name().unwrap().to_str().unwrap();
+        let content = read_to_string(&file).unwrap();
+        assert!(name.contains("-ServerError-") && name.ends_with(".rs"));
+        assert!(content.starts_with("// The implementations generated by the `Mabe` derive macro for the"));
+        assert!(content.contains("second") && !content.contains("first"));
+
+        // Example 3: The enums with the same name but different variants (e.g. in different modules) are written to different
+        // files.
+        assert!(dump_macro_output(&quote! { impl ServerError {} }, &other_server_error).is_none());
+        assert_eq!(files(), 2);
+
+        // Example 4: A failed write returns a warning instead of panicking (the directory is a file).
+        set_var(EXPAND_DIR_VAR, &file);
+        let warning = dump_macro_output(&quote! { impl ServerError {} }, &server_error).unwrap().to_string();
+        assert!(warning.contains("deprecated") && warning.contains("[warning] Failed to write the output"));
+        remove_var(EXPAND_DIR_VAR);
+
+        remove_dir_all(&dir).unwrap();
+    }
+}
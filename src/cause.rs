@@ -0,0 +1,323 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use crate::MabeError;
+use core::error::Error;
+
+/// The cause of an error (i.e. its source), which is either an error that derives [`Mabe`](derive@crate::Mabe) or a foreign
+/// error. The causes of the errors that derive [`Mabe`](derive@crate::Mabe) are returned by [`MabeError::mabe_source`].
+#[derive(Clone, Copy, Debug)]
+pub enum Cause<'a> {
+    /// An error that derives [`Mabe`](derive@crate::Mabe), whose error and debug messages can be rendered separately.
+    Mabe(&'a dyn MabeError),
+    /// Any other error, which is rendered with its [`Display`](core::fmt::Display) implementation.
+    Foreign(&'a (dyn Error + 'static)),
+}
+
+impl<'a> Cause<'a> {
+    /// Returns the cause as a standard error.
+    pub fn as_error(&self) -> &'a (dyn Error + 'static) {
+        match *self {
+            Self::Mabe(error) => error.as_error(),
+            Self::Foreign(error) => error,
+        }
+    }
+
+    /// Returns the cause of the cause, or `None` if it doesn't have one.
+    pub fn next(&self) -> Option<Cause<'a>> {
+        match *self {
+            Self::Mabe(error) => error.mabe_source(),
+            Self::Foreign(error) => error.source().map(Cause::Foreign),
+        }
+    }
+}
+
+/// The items used by the generated code to implement the `source` method of the [`Error`] trait and the
+/// [`mabe_source`](MabeError::mabe_source) method of the [`MabeError`] trait, and to render the messages, which are not part of
+/// the public API.
+#[doc(hidden)]
+pub mod private {
+    use super::Cause;
+    use crate::{MabeError, VariantInfo};
+    #[cfg(feature = "alloc")]
+    use alloc::boxed::Box;
+    use core::error::Error;
+    use core::fmt;
+
+    /// A writer that writes its prefix before the first non-empty string written into it, so that the line of a message (e.g.
+    /// the `[debug]` line) is skipped if the message renders to an empty string.
+    pub struct PrefixedWriter<'a, W: ?Sized> {
+        writer: &'a mut W,
+        prefix: Option<fmt::Arguments<'a>>,
+    }
+
+    impl<'a, W: fmt::Write + ?Sized> PrefixedWriter<'a, W> {
+        pub fn new(writer: &'a mut W, prefix: fmt::Arguments<'a>) -> Self {
+            Self { writer, prefix: Some(prefix) }
+        }
+    }
+
+    impl<W: fmt::Write + ?Sized> fmt::Write for PrefixedWriter<'_, W> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            if s.is_empty() {
+                return Ok(());
+            }
+
+            if let Some(prefix) = self.prefix.take() {
+                self.writer.write_fmt(prefix)?;
+            }
+
+            self.writer.write_str(s)
+        }
+    }
+
+    /// Creates the metadata of a variant in the `VARIANTS` table of an enum, whose other fields are set by the hidden builder
+    /// methods of [`VariantInfo`].
+    pub const fn variant_info(
+        name: &'static str,
+        error_template: &'static str,
+        debug_template: &'static str,
+        error_placeholders: &'static [&'static str],
+        debug_placeholders: &'static [&'static str],
+    ) -> VariantInfo {
+        VariantInfo::new(name, error_template, debug_template, error_placeholders, debug_placeholders)
+    }
+
+    /// Returns the names of the variants of the `VARIANTS` table of an enum, which has `N` variants.
+    pub const fn variant_names<const N: usize>(variants: &[VariantInfo]) -> [&'static str; N] {
+        let mut names = [""; N];
+        let mut i = 0;
+
+        while i < N {
+            names[i] = variants[i].name();
+            i += 1;
+        }
+
+        names
+    }
+
+    /// The single dispatch of the generated code, which passes the messages and the fields of a variant to the visitor. The
+    /// variants whose messages are their raw templates (i.e. the unit variants without any placeholder) only pass their
+    /// metadata, so that they don't need a match arm.
+    pub trait Visitor {
+        fn unit(&mut self, info: &'static VariantInfo) -> fmt::Result;
+
+        fn tuple(&mut self, error: fmt::Arguments<'_>, debug: fmt::Arguments<'_>, fields: &[&dyn fmt::Debug]) -> fmt::Result;
+
+        fn named(
+            &mut self,
+            error: fmt::Arguments<'_>,
+            debug: fmt::Arguments<'_>,
+            fields: &[(&str, &dyn fmt::Debug)],
+        ) -> fmt::Result;
+    }
+
+    /// A visitor that writes the error (or debug) message of the variant into a writer.
+    pub struct MsgWriter<'a, W: ?Sized> {
+        writer: &'a mut W,
+        debug: bool,
+    }
+
+    impl<'a, W: fmt::Write + ?Sized> MsgWriter<'a, W> {
+        pub fn new(writer: &'a mut W, debug: bool) -> Self {
+            Self { writer, debug }
+        }
+    }
+
+    impl<W: fmt::Write + ?Sized> Visitor for MsgWriter<'_, W> {
+        fn unit(&mut self, info: &'static VariantInfo) -> fmt::Result {
+            match self.debug {
+                false => self.writer.write_str(info.error_template()),
+                true => self.writer.write_str(info.debug_template()),
+            }
+        }
+
+        fn tuple(&mut self, error: fmt::Arguments<'_>, debug: fmt::Arguments<'_>, _: &[&dyn fmt::Debug]) -> fmt::Result {
+            match self.debug {
+                false => self.writer.write_fmt(error),
+                true => self.writer.write_fmt(debug),
+            }
+        }
+
+        fn named(
+            &mut self,
+            error: fmt::Arguments<'_>,
+            debug: fmt::Arguments<'_>,
+            _: &[(&str, &dyn fmt::Debug)],
+        ) -> fmt::Result {
+            self.tuple(error, debug, &[])
+        }
+    }
+
+    /// A visitor that retrieves the error (or debug) message of the variant if it does not depend on any field.
+    pub struct StaticMsg {
+        debug: bool,
+        msg: Option<&'static str>,
+    }
+
+    impl StaticMsg {
+        pub fn new(debug: bool) -> Self {
+            Self { debug, msg: None }
+        }
+
+        pub fn get(&self) -> Option<&'static str> {
+            self.msg
+        }
+    }
+
+    impl Visitor for StaticMsg {
+        fn unit(&mut self, info: &'static VariantInfo) -> fmt::Result {
+            self.msg = match self.debug {
+                false => Some(info.error_template()),
+                true => Some(info.debug_template()),
+            };
+            Ok(())
+        }
+
+        fn tuple(&mut self, error: fmt::Arguments<'_>, debug: fmt::Arguments<'_>, _: &[&dyn fmt::Debug]) -> fmt::Result {
+            self.msg = match self.debug {
+                false => error.as_str(),
+                true => debug.as_str(),
+            };
+            Ok(())
+        }
+
+        fn named(
+            &mut self,
+            error: fmt::Arguments<'_>,
+            debug: fmt::Arguments<'_>,
+            _: &[(&str, &dyn fmt::Debug)],
+        ) -> fmt::Result {
+            self.tuple(error, debug, &[])
+        }
+    }
+
+    /// A visitor that writes the state of the variant like `#[derive(Debug)]` would (i.e. the name of the enum and the variant,
+    /// followed by the `Debug` implementations of its fields), which also supports the alternate `{:#?}` form.
+    pub struct StateWriter<'a, 'b> {
+        f: &'a mut fmt::Formatter<'b>,
+        enum_name: &'static str,
+        variant_name: &'static str,
+    }
+
+    impl<'a, 'b> StateWriter<'a, 'b> {
+        pub fn new(f: &'a mut fmt::Formatter<'b>, enum_name: &'static str, variant_name: &'static str) -> Self {
+            Self { f, enum_name, variant_name }
+        }
+
+        // The name of the enum is written before the builders of the formatter, which then write the name of the variant.
+        fn write_enum_name(&mut self) -> fmt::Result {
+            self.f.write_str(self.enum_name)?;
+            self.f.write_str("::")
+        }
+    }
+
+    impl Visitor for StateWriter<'_, '_> {
+        fn unit(&mut self, _: &'static VariantInfo) -> fmt::Result {
+            self.write_enum_name()?;
+            self.f.write_str(self.variant_name)
+        }
+
+        fn tuple(&mut self, _: fmt::Arguments<'_>, _: fmt::Arguments<'_>, fields: &[&dyn fmt::Debug]) -> fmt::Result {
+            self.write_enum_name()?;
+            let mut state = self.f.debug_tuple(self.variant_name);
+            fields.iter().for_each(|value| {
+                state.field(value);
+            });
+            state.finish()
+        }
+
+        fn named(&mut self, _: fmt::Arguments<'_>, _: fmt::Arguments<'_>, fields: &[(&str, &dyn fmt::Debug)]) -> fmt::Result {
+            self.write_enum_name()?;
+            let mut state = self.f.debug_struct(self.variant_name);
+            fields.iter().for_each(|(name, value)| {
+                state.field(name, value);
+            });
+            state.finish()
+        }
+    }
+
+    /// Converts the sources of the errors into trait objects, including the sources that are already trait objects (e.g.
+    /// `Box<dyn Error + Send + Sync>`, through auto-deref).
+    pub trait AsDynError {
+        fn as_dyn_error(&self) -> &(dyn Error + 'static);
+    }
+
+    impl<T: Error + 'static> AsDynError for T {
+        fn as_dyn_error(&self) -> &(dyn Error + 'static) {
+            self
+        }
+    }
+
+    macro_rules! impl_as_dyn_error {
+        ($($ty:ty),*) => {
+            $(impl AsDynError for $ty {
+                fn as_dyn_error(&self) -> &(dyn Error + 'static) {
+                    self
+                }
+            })*
+        };
+    }
+
+    impl_as_dyn_error!(dyn Error + 'static, dyn Error + Send + 'static, dyn Error + Send + Sync + 'static);
+
+    // The trait objects of `MabeError` are converted with the `as_error` method rather than with trait upcasting, which would
+    // raise the minimum supported Rust version.
+    macro_rules! impl_mabe_as_dyn_error {
+        ($($ty:ty),*) => {
+            $(impl AsDynError for $ty {
+                fn as_dyn_error(&self) -> &(dyn Error + 'static) {
+                    self.as_error()
+                }
+            })*
+        };
+    }
+
+    impl_mabe_as_dyn_error!(dyn MabeError, dyn MabeError + Send, dyn MabeError + Send + Sync);
+
+    /// Wraps the source of an error, so that the `__mabe_cause` method resolves to [`MabeCause`] if the source derives `Mabe`
+    /// and to [`ForeignCause`] otherwise (i.e. autoref-based specialization).
+    pub struct CauseWrap<'a, T: ?Sized>(pub &'a T);
+
+    impl<T: ?Sized> Clone for CauseWrap<'_, T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T: ?Sized> Copy for CauseWrap<'_, T> {}
+
+    pub trait MabeCause<'a> {
+        fn __mabe_cause(self, error: &'a (dyn Error + 'static)) -> Cause<'a>;
+    }
+
+    impl<'a, T: MabeError> MabeCause<'a> for &CauseWrap<'a, T> {
+        fn __mabe_cause(self, _: &'a (dyn Error + 'static)) -> Cause<'a> {
+            Cause::Mabe(self.0)
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    macro_rules! impl_mabe_cause {
+        ($($ty:ty),*) => {
+            $(impl<'a> MabeCause<'a> for &CauseWrap<'a, Box<$ty>> {
+                fn __mabe_cause(self, _: &'a (dyn Error + 'static)) -> Cause<'a> {
+                    Cause::Mabe(&**self.0)
+                }
+            })*
+        };
+    }
+
+    #[cfg(feature = "alloc")]
+    impl_mabe_cause!(dyn MabeError, dyn MabeError + Send, dyn MabeError + Send + Sync);
+
+    pub trait ForeignCause<'a> {
+        fn __mabe_cause(self, error: &'a (dyn Error + 'static)) -> Cause<'a>;
+    }
+
+    impl<'a, T: ?Sized> ForeignCause<'a> for CauseWrap<'a, T> {
+        fn __mabe_cause(self, error: &'a (dyn Error + 'static)) -> Cause<'a> {
+            Cause::Foreign(error)
+        }
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use crate::report::PREFIXES;
+use crate::{Cause, MabeError, VariantInfo};
+use alloc::string::{String, ToString};
+use core::any::Any;
+use core::error::Error;
+use core::fmt;
+
+/// A wrapper that attaches a debug note to an error, which is rendered as an extra `[debug]` line after the
+/// [`Display`](fmt::Display) implementation of the error. The wrapper is transparent: its source is the source of the error,
+/// and it implements [`MabeError`] if the error does (in which case the note is written by the
+/// [`write_notes`](MabeError::write_notes) method, so that a [`Report`](crate::Report) renders it the same way). Wrappers are
+/// created by the [`with_debug`](ResultExt::with_debug) method, and can be nested to attach several notes.
+///
+/// The wrapper only implements [`Error`] if the error does, which is not the case of `Box<dyn Error>` (a boxed error can
+/// still be given a note, but it must be wrapped into a variant with the [`mabe_context`](ResultExt::mabe_context) method to
+/// be used as an error).
+pub struct Context<E> {
+    error: E,
+    note: String,
+}
+
+impl<E> Context<E> {
+    /// Attaches a debug note to the given error.
+    pub fn new<D: fmt::Display>(error: E, note: D) -> Self {
+        Self { error, note: note.to_string() }
+    }
+
+    /// Returns a reference to the wrapped error.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Returns the debug note.
+    pub fn note(&self) -> &str {
+        &self.note
+    }
+
+    /// Returns the wrapped error.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E: fmt::Debug> fmt::Debug for Context<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Context").field("error", &self.error).field("note", &self.note).finish()
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Context<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)?;
+        f.write_fmt(format_args!("\n{} {}", PREFIXES.1, self.note))
+    }
+}
+
+impl<E: Error> Error for Context<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<E: MabeError> MabeError for Context<E> {
+    fn variant_info(&self) -> &'static VariantInfo {
+        self.error.variant_info()
+    }
+
+    fn variant_name(&self) -> &'static str {
+        self.error.variant_name()
+    }
+
+    fn mabe_source(&self) -> Option<Cause<'_>> {
+        self.error.mabe_source()
+    }
+
+    fn state(&self) -> String {
+        self.error.state()
+    }
+
+    fn error(&self) -> String {
+        self.error.error()
+    }
+
+    fn debug(&self) -> String {
+        self.error.debug()
+    }
+
+    fn write_notes(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        self.error.write_notes(w)?;
+        w.write_fmt(format_args!("\n{} {}", PREFIXES.1, self.note))
+    }
+
+    fn write_error(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        self.error.write_error(w)
+    }
+
+    fn write_debug(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        self.error.write_debug(w)
+    }
+
+    fn error_str(&self) -> Option<&'static str> {
+        self.error.error_str()
+    }
+
+    fn debug_str(&self) -> Option<&'static str> {
+        self.error.debug_str()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn as_error(&self) -> &(dyn Error + 'static) {
+        self
+    }
+}
+
+/// An extension trait for results, which adds situational context to their errors without defining a variant for every call
+/// site. It is implemented for any error type, including the boxed errors (e.g. `Box<dyn Error + Send + Sync>`) that don't
+/// implement [`Error`] themselves.
+pub trait ResultExt<T, E> {
+    /// Wraps the error into a variant of an enum that derives [`Mabe`](derive@crate::Mabe), by calling the given function
+    /// (e.g. the constructor of a tuple variant whose field has the `source` option) with the error.
+    fn mabe_context<C: MabeError, F: FnOnce(E) -> C>(self, context: F) -> Result<T, C>;
+
+    /// Attaches the debug note returned by the given function to the error, which is only called if the result is an error.
+    fn with_debug<D: fmt::Display, F: FnOnce() -> D>(self, note: F) -> Result<T, Context<E>>;
+}
+
+impl<T, E> ResultExt<T, E> for Result<T, E> {
+    fn mabe_context<C: MabeError, F: FnOnce(E) -> C>(self, context: F) -> Result<T, C> {
+        self.map_err(context)
+    }
+
+    fn with_debug<D: fmt::Display, F: FnOnce() -> D>(self, note: F) -> Result<T, Context<E>> {
+        self.map_err(|error| Context::new(error, note()))
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use crate::Mabe;
+use alloc::string::String;
+
+/// The error returned by the generated `FromStr` and `TryFrom` implementations when the input doesn't match any unit variant
+/// of the enum (i.e. when it is neither the name nor the code of a unit variant, or not the integer code of one).
+#[derive(Mabe)]
+#[mabe(crate = "crate", no_std)]
+pub enum ConversionError {
+    /// The input is neither the name nor the code of a unit variant.
+    #[error("`{input}` is not the name or the code of a unit variant of the `{enum_name}` enum.")]
+    UnknownName {
+        /// The name of the enum.
+        enum_name: &'static str,
+        /// The input that was parsed.
+        input: String,
+    },
+
+    /// The input is not the integer code of a unit variant.
+    #[error("`{code}` is not the integer code of a unit variant of the `{enum_name}` enum.")]
+    UnknownCode {
+        /// The name of the enum.
+        enum_name: &'static str,
+        /// The integer code that was converted.
+        code: i64,
+    },
+}
@@ -0,0 +1,187 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use crate::{MabeError, Severity};
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+/// A collection of errors that are reported together rather than one at a time (e.g. all the problems found by a validator).
+/// The errors are deduplicated by variant and fields (i.e. by their `state`, see [`push`](Self::push)), and are kept sorted
+/// from the most to the least severe, in the order of their insertion for the errors of the same severity. The
+/// [`Display`](fmt::Display) implementation renders the errors grouped by severity, each group under a heading (e.g.
+/// `errors:`), followed by a summary line (e.g. `3 errors, 2 warnings`).
+pub struct Diagnostics<E> {
+    errors: Vec<E>,
+    states: BTreeSet<String>,
+}
+
+impl<E: MabeError> Diagnostics<E> {
+    /// Creates an empty collection.
+    pub const fn new() -> Self {
+        Self { errors: Vec::new(), states: BTreeSet::new() }
+    }
+
+    /// Adds an error to the collection, and returns `false` if the collection already holds the same error (in which case it
+    /// is not added again).
+    ///
+    /// The errors are compared by their [`state`](MabeError::state), which renders the redacted fields as `***` and hides the
+    /// backtraces and locations, so two errors of the same variant that only differ by these fields are considered the same,
+    /// and only the first one is kept.
+    /// The errors that must all be kept regardless (e.g. to be inspected later) should be stored in a `Vec` instead.
+    pub fn push(&mut self, error: E) -> bool {
+        if !self.states.insert(error.state()) {
+            return false;
+        }
+
+        let index = self.errors.partition_point(|other| other.severity() >= error.severity());
+        self.errors.insert(index, error);
+        true
+    }
+
+    /// Returns the number of errors in the collection.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns `true` if the collection holds no error.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns an iterator over the errors, from the most to the least severe.
+    pub fn iter(&self) -> core::slice::Iter<'_, E> {
+        self.errors.iter()
+    }
+
+    /// Returns the number of errors of the given severity.
+    pub fn count(&self, severity: Severity) -> usize {
+        self.errors.iter().filter(|error| error.severity() == severity).count()
+    }
+
+    /// Returns the highest severity of the errors, or `None` if the collection is empty.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.errors.first().map(|error| error.severity())
+    }
+
+    /// Returns `true` if the collection holds at least one error whose severity is [`Severity::Error`] or higher.
+    pub fn has_errors(&self) -> bool {
+        self.max_severity().is_some_and(|severity| severity >= Severity::Error)
+    }
+
+    /// Returns `Ok(())` if the collection doesn't hold any error whose severity is [`Severity::Error`] or higher, and the
+    /// collection itself otherwise.
+    pub fn into_result(self) -> Result<(), Self> {
+        match self.has_errors() {
+            true => Err(self),
+            false => Ok(()),
+        }
+    }
+
+    /// Writes the summary line of the collection (e.g. `3 errors, 2 warnings`), which counts the errors of each severity from
+    /// the most to the least severe.
+    pub fn write_summary(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        let mut first = true;
+
+        for severity in [Severity::Fatal, Severity::Error, Severity::Warning, Severity::Note] {
+            let count = self.count(severity);
+
+            if count > 0 {
+                let plural = if count > 1 { "s" } else { "" };
+
+                w.write_str(if first { "" } else { ", " })?;
+                w.write_fmt(format_args!("{} {}{}", count, label(severity), plural))?;
+                first = false;
+            }
+        }
+
+        match first {
+            true => w.write_str("no diagnostics"),
+            false => Ok(()),
+        }
+    }
+
+    /// Returns the summary line of the collection (e.g. `3 errors, 2 warnings`).
+    pub fn summary(&self) -> String {
+        let mut summary = String::new();
+        let _ = self.write_summary(&mut summary);
+        summary
+    }
+}
+
+impl<E: MabeError> Default for Diagnostics<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: MabeError> Extend<E> for Diagnostics<E> {
+    fn extend<I: IntoIterator<Item = E>>(&mut self, errors: I) {
+        for error in errors {
+            self.push(error);
+        }
+    }
+}
+
+impl<E: MabeError> FromIterator<E> for Diagnostics<E> {
+    fn from_iter<I: IntoIterator<Item = E>>(errors: I) -> Self {
+        let mut diagnostics = Self::new();
+        diagnostics.extend(errors);
+        diagnostics
+    }
+}
+
+impl<E> IntoIterator for Diagnostics<E> {
+    type Item = E;
+    type IntoIter = alloc::vec::IntoIter<E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a Diagnostics<E> {
+    type Item = &'a E;
+    type IntoIter = core::slice::Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+impl<E: MabeError> fmt::Debug for Diagnostics<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.errors.iter()).finish()
+    }
+}
+
+impl<E: MabeError> fmt::Display for Diagnostics<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut severity = None;
+
+        // The errors are sorted by severity, so each group of errors of the same severity is rendered under a single heading.
+        for error in &self.errors {
+            if severity != Some(error.severity()) {
+                severity = Some(error.severity());
+                f.write_fmt(format_args!("\n{}s:", label(error.severity())))?;
+            }
+
+            fmt::Display::fmt(error, f)?;
+        }
+
+        f.write_str("\n")?;
+        self.write_summary(f)
+    }
+}
+
+impl<E: MabeError> Error for Diagnostics<E> {}
+
+/// Returns the label of the given severity in the summary line and in the headings of the groups of errors.
+fn label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Fatal => "fatal error",
+        severity => severity.as_str(),
+    }
+}
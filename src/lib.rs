@@ -5,8 +5,9 @@
 //! variant in the enum can encapsulate an error and a debug message, and errors are presented in a structured format,
 //! displaying the messages defined for the variant. This allows for a more detailed and clear debugging process.
 //!
-//! Functionally, this crate is a _procedural macro_ that provides a derive macro called
-//! [`Mabe`](https://docs.rs/mabe/latest/mabe/derive.Mabe.html), which is used to generate the debug-friendly error enums.
+//! Functionally, this crate re-exports the [`Mabe`](derive@Mabe) derive macro from the `mabe-derive` crate, which is used to
+//! generate the debug-friendly error enums, and provides the [`MabeError`] trait, which is implemented by every enum that
+//! derives [`Mabe`](derive@Mabe).
 //!
 //! # Examples
 //!
@@ -65,28 +66,894 @@
 //! [debug] Retry in 10 seconds.
 //! ```
 //!
+//! Placeholders that refer to a field can also carry a format spec, which is useful for fields that only implement
+//! [`Debug`](core::fmt::Debug). The [`Debug`](core::fmt::Debug) implementation of the enum (and its `state` method) renders the
+//! fields like `#[derive(Debug)]` would, and supports the alternate `{:#?}` form:
+//!
+//! ```
+//! use mabe::Mabe;
+//!
+//! #[derive(Debug)]
+//! pub struct Request { id: u32 }
+//!
+//! #[derive(Mabe)]
+//! pub enum ServerError {
+//!     // Interpolates the `Debug` representation of the `request` field in the error message.
+//!     #[error("Invalid request. --> {request:?}.")]
+//!     InvalidRequest { request: Request },
+//! }
+//!
+//! let error = ServerError::InvalidRequest { request: Request { id: 7 } };
+//! println!("{}", error);
+//! println!("{:?}", error);
+//! ```
+//!
+//! ```text
+//! Output:
+//! [error] Invalid request. --> Request { id: 7 }.
+//! ServerError::InvalidRequest { request: Request { id: 7 } }
+//! ```
+//!
+//! Fields that hold secrets can be redacted with the `mabe` attribute, in which case they are rendered as `***` (or as the
+//! given replacement text) by the `state` method and the [`Debug`](core::fmt::Debug) implementation. Redacted fields don't need
+//! to be interpolated in the messages:
+//!
+//! ```
+//! use mabe::Mabe;
+//!
+//! #[derive(Mabe)]
+//! pub enum ServerError {
+//!     #[error("Authentication failed for {user}.")]
+//!     AuthFailed { user: String, #[mabe(redact)] password: String },
+//! }
+//!
+//! let error = ServerError::AuthFailed { user: "admin".to_string(), password: "hunter2".to_string() };
+//! assert_eq!(error.state(), "ServerError::AuthFailed { user: \"admin\", password: *** }");
+//! ```
+//!
+//! Redacted fields that are interpolated in the debug message are rendered as their replacement text (without their format
+//! specs). Interpolating a redacted field in the error message is a compile-time error though, unless the `interpolate` option
+//! is added to the attribute, in which case the replacement text is interpolated instead of the value of the field:
+//!
+//! ```compile_fail
+//! use mabe::Mabe;
+//!
+//! #[derive(Mabe)]
+//! pub enum ServerError {
+//!     #[error("Invalid token: {0}.")]
+//!     InvalidToken(#[mabe(redact)] String),
+//! }
+//! ```
+//!
+//! The messages can also be written into any [`Write`](core::fmt::Write) implementation without allocating intermediate
+//! strings, and the messages that don't interpolate any field are available as string literals:
+//!
+//! ```
+//! use mabe::Mabe;
+//!
+//! #[derive(Mabe)]
+//! pub enum ServerError {
+//!     #[error("You are not authorized to access this resource.")]
+//!     Unauthorized,
+//!
+//!     #[error("Network failure. --> Code {0}.")]
+//!     NetworkFailure(u32),
+//! }
+//!
+//! let mut log = String::new();
+//! ServerError::NetworkFailure(404).write_error(&mut log).unwrap();
+//! assert_eq!(log, "Network failure. --> Code 404.");
+//!
+//! assert_eq!(ServerError::Unauthorized.error_str(), Some("You are not authorized to access this resource."));
+//! assert_eq!(ServerError::NetworkFailure(404).error_str(), None);
+//! ```
+//!
+//! By default, every field must be interpolated in the error or debug message of its variant. Fields that are prefixed with an
+//! underscore or that have the `skip` option are exempt from this rule, and the `unused_fields` option of the enum sets the
+//! policy for the other fields: `"deny"` (the default) rejects them, `"warn"` accepts them with a compiler warning (which can
+//! be silenced with `#[allow(deprecated)]`), and `"allow"` accepts them silently:
+//!
+//! ```
+//! use mabe::Mabe;
+//!
+//! #[derive(Mabe)]
+//! #[mabe(unused_fields = "warn")]
+//! pub enum ServerError {
+//!     #[error("The query {query} failed.")]
+//!     QueryFailed { query: String, #[mabe(skip)] request_id: u64, _elapsed_ms: u128 },
+//! }
+//! ```
+//!
+//! # `no_std` Support
+//!
+//! The `no_std` option of the enum makes the generated code only depend on the `core` and `alloc` crates (the crate must then
+//! declare `extern crate alloc;`), and implement [`core::error::Error`] instead of
+//! [`std::error::Error`](https://doc.rust-lang.org/std/error/trait.Error.html). The `no_alloc` option goes further and only
+//! depends on the `core` crate, in which case the `state`, `error`, and `debug` methods (as well as the implementation of the
+//! [`MabeError`] trait) are not generated, but the messages can still be written into any [`Write`](core::fmt::Write)
+//! implementation. The `alloc` feature of the crate can then be disabled, so that the crate doesn't depend on the `alloc` crate
+//! either:
+//!
+//! ```
+//! use mabe::Mabe;
+//!
+//! #[derive(Mabe)]
+//! #[mabe(no_alloc)]
+//! pub enum FirmwareError {
+//!     #[error("The sensor {0} is not responding.")]
+//!     #[debug("Check the wiring of the sensor.")]
+//!     SensorTimeout(u8),
+//! }
+//! ```
+//!
+//! # Re-exporting the Crate
+//!
+//! The generated code only uses fully qualified paths (e.g. `::core::fmt`), so it is not affected by the items of the crate
+//! the enum is defined in. If `mabe` is re-exported by another crate, the path to use for its runtime items can be set with the
+//! `crate` option of the enum:
+//!
+//! ```
+//! mod errors {
+//!     pub use mabe::*;
+//! }
+//!
+//! use errors::Mabe;
+//!
+//! #[derive(Mabe)]
+//! #[mabe(crate = "errors")]
+//! pub enum ServerError {
+//!     #[error("You are not authorized to access this resource.")]
+//!     Unauthorized,
+//! }
+//! ```
+//!
+//! # Inspecting the Generated Code
+//!
+//! If the `MABE_EXPAND_DIR` environment variable is set when building a crate, the code generated for each enum of that crate
+//! is written to a `<crate>-<enum>-<hash>.rs` file in the given directory, where the hash is computed from the names of the
+//! variants of the enum (so that the enums that have the same name in different modules get different files). The file of an
+//! enum is overwritten every time the enum is expanded and is formatted with `rustfmt` when it is available, and a failed write
+//! only emits a warning:
+//!
+//! ```sh
+//! MABE_EXPAND_DIR=target/mabe cargo build
+//! ```
+//!
+//! Note that the derive macro only runs when the crate is recompiled, so the crate might need to be touched (or cleaned) for
+//! the files to be written.
+//!
+//! # Generic Error Handling
+//!
+//! Every enum that derives [`Mabe`](derive@Mabe) implements the [`MabeError`] trait, which exposes the same methods as the
+//! generated inherent ones. The trait is object-safe, so errors of different enums can be handled by the same generic code or
+//! stored together as trait objects, and then downcast back to their concrete type:
+//!
+//! ```
+//! use mabe::{Mabe, MabeError};
+//!
+//! #[derive(Mabe)]
+//! enum ServerError {
+//!     #[error("You are not authorized to access this resource.")]
+//!     Unauthorized,
+//! }
+//!
+//! #[derive(Mabe)]
+//! enum ClientError {
+//!     #[error("The request timed out after {0} seconds.")]
+//!     #[debug("Try again later.")]
+//!     Timeout(u32),
+//! }
+//!
+//! fn report<E: MabeError>(error: &E) -> String {
+//!     format!("{}: {}", error.variant_name(), error.error())
+//! }
+//!
+//! assert_eq!(report(&ClientError::Timeout(30)), "Timeout: The request timed out after 30 seconds.");
+//!
+//! let errors: Vec<Box<dyn MabeError>> = vec![Box::new(ServerError::Unauthorized), Box::new(ClientError::Timeout(30))];
+//! assert!(errors[0].is::<ServerError>());
+//! assert!(matches!(errors[1].downcast_ref::<ClientError>(), Some(ClientError::Timeout(30))));
+//! ```
+//!
+//! # Error Kinds
+//!
+//! The `kind` option of the enum generates a fieldless `<Enum>Kind` enum alongside it, with one unit variant per variant of
+//! the enum, and a `kind` method that returns the kind of a variant. The kinds implement [`Copy`], [`Eq`], and
+//! [`Hash`](core::hash::Hash), so they can be matched on without the fields of the variants or used as keys in a map, and
+//! their `as_str` method (and their [`Display`](core::fmt::Display) implementation) returns the name of the variant:
+//!
+//! ```
+//! use mabe::Mabe;
+//!
+//! #[derive(Mabe)]
+//! #[mabe(kind)]
+//! enum ServerError {
+//!     #[error("The query {0} failed.")]
+//!     QueryFailed(String),
+//! }
+//!
+//! let error = ServerError::QueryFailed("SELECT 1".to_string());
+//! assert_eq!(error.kind(), ServerErrorKind::QueryFailed);
+//! assert_eq!(error.kind().as_str(), "QueryFailed");
+//! ```
+//!
+//! # Variant Introspection
+//!
+//! The `VARIANTS`, `VARIANT_NAMES`, and `VARIANT_COUNT` constants of the enum describe all of its variants, and the
+//! `variant_info` and `variant_name` methods describe the variant of an error. The metadata of each variant (i.e. its
+//! [`VariantInfo`]) holds its name, its raw error and debug messages, and the fields interpolated in them, and is available in
+//! `const` contexts:
+//!
+//! ```
+//! use mabe::Mabe;
+//!
+//! #[derive(Mabe)]
+//! enum ServerError {
+//!     #[error("You are not authorized to access this resource.")]
+//!     Unauthorized,
+//!     #[error("The query {query} failed.")]
+//!     QueryFailed { query: String },
+//! }
+//!
+//! const NAMES: &[&str] = ServerError::VARIANT_NAMES;
+//! assert_eq!(NAMES, ["Unauthorized", "QueryFailed"]);
+//!
+//! let info = ServerError::VARIANTS[1];
+//! assert_eq!(info.error_template(), "The query {query} failed.");
+//! assert_eq!(info.error_placeholders(), ["query"]);
+//! ```
+//!
+//! # Error Codes
+//!
+//! The `code` option of a variant sets its code, which is returned by the `code` method of the enum. String codes are used as
+//! is, while numeric codes are prefixed with the `code_prefix` option of the enum and padded with zeros up to its `code_width`
+//! option (`4` by default). If the `code_prefix` option is set, the variants that don't have a code are numbered after their
+//! discriminants. The `show_code` option of the enum displays the code in the error line, and two variants of the same enum
+//! cannot have the same code:
+//!
+//! ```
+//! use mabe::Mabe;
+//!
+//! #[derive(Mabe)]
+//! #[mabe(code_prefix = "AUTH-", show_code)]
+//! enum AuthError {
+//!     #[error("The token has expired.")]
+//!     ExpiredToken = 3,
+//!     #[error("The token is invalid.")]
+//!     InvalidToken,
+//!     #[mabe(code = "AUTH-LEGACY")]
+//!     #[error("The legacy token format is not supported.")]
+//!     LegacyToken,
+//! }
+//!
+//! assert_eq!(AuthError::ExpiredToken.code(), Some("AUTH-0003"));
+//! assert_eq!(AuthError::InvalidToken.code(), Some("AUTH-0004"));
+//! assert_eq!(AuthError::LegacyToken.code(), Some("AUTH-LEGACY"));
+//! assert!(AuthError::ExpiredToken.to_string().contains("[AUTH-0003] The token has expired."));
+//! ```
+//!
+//! # Explanations
+//!
+//! The `explain` attribute of a variant sets its long-form explanation (in the spirit of `rustc --explain`), which is returned
+//! by the `explain` method of the enum. The attribute takes any expression of type `&'static str`, such as a string literal or
+//! an `include_str!` invocation, and the `explain_code` function of the enum looks the explanations up by code:
+//!
+//! ```
+//! use mabe::Mabe;
+//!
+//! #[derive(Mabe)]
+//! enum DbError {
+//!     #[mabe(code = "E0042")]
+//!     #[error("The connection to the database was refused.")]
+//!     #[explain("Make sure that the database is running and that the connection string is correct.")]
+//!     ConnectionRefused,
+//! }
+//!
+//! assert!(DbError::ConnectionRefused.explain().unwrap().starts_with("Make sure"));
+//! assert_eq!(DbError::explain_code("E0042"), DbError::ConnectionRefused.explain());
+//! ```
+//!
+//! # Help URLs
+//!
+//! The `url` option of a variant and the `docs_base` option of the enum set the help URL of the variants, which is returned by
+//! the `help_url` method of the enum. The `{code}` and `{variant}` placeholders of the URLs are replaced with the code and the
+//! name of the variant respectively (the variants without a code have no URL if it refers to their code), and the `show_url`
+//! option of the enum displays the URL in a `[see]` line after the error and debug lines:
+//!
+//! ```
+//! use mabe::Mabe;
+//!
+//! #[derive(Mabe)]
+//! #[mabe(docs_base = "https://docs.example.com/errors/{code}", show_url)]
+//! enum DbError {
+//!     #[mabe(code = "E0042")]
+//!     #[error("The connection to the database was refused.")]
+//!     ConnectionRefused,
+//! }
+//!
+//! assert_eq!(DbError::ConnectionRefused.help_url(), Some("https://docs.example.com/errors/E0042"));
+//! assert!(DbError::ConnectionRefused.to_string().ends_with(" https://docs.example.com/errors/E0042"));
+//! ```
+//!
+//! # Severities
+//!
+//! The `severity` option of a variant (or of the enum, for all of its variants) sets its [`Severity`], which is returned by the
+//! `severity` method of the enum and is `"error"` by default. The label of the severity is used as the prefix of the error line
+//! (e.g. `[warning]` instead of `[error]`), so that the same enum can model notes, warnings, errors, and fatal errors:
+//!
+//! ```
+//! use mabe::{Mabe, Severity};
+//!
+//! #[derive(Mabe)]
+//! #[mabe(severity = "warning")]
+//! enum Lint {
+//!     #[error("The variable {0} is never used.")]
+//!     UnusedVariable(String),
+//!     #[mabe(severity = "fatal")]
+//!     #[error("The configuration file is missing.")]
+//!     MissingConfig,
+//! }
+//!
+//! assert_eq!(Lint::UnusedVariable("x".to_string()).severity(), Severity::Warning);
+//! assert!(Lint::MissingConfig.severity() > Severity::Error);
+//! ```
+//!
+//! # Reporting Many Errors at Once
+//!
+//! The [`Diagnostics`] collection accumulates errors so that they can be reported together rather than one at a time. It
+//! ignores the errors that it already holds, keeps them sorted from the most to the least severe, renders them grouped under a
+//! heading per severity and followed by a summary line, and is itself an error:
+//!
+//! ```
+//! use mabe::{Diagnostics, Mabe};
+//!
+//! #[derive(Mabe)]
+//! enum ConfigError {
+//!     #[error("The {0} key is missing.")]
+//!     MissingKey(String),
+//!     #[mabe(severity = "warning")]
+//!     #[error("The {0} key is deprecated.")]
+//!     DeprecatedKey(String),
+//! }
+//!
+//! let mut diagnostics = Diagnostics::new();
+//! diagnostics.push(ConfigError::DeprecatedKey("timeout".to_string()));
+//! diagnostics.push(ConfigError::MissingKey("host".to_string()));
+//! diagnostics.push(ConfigError::MissingKey("host".to_string()));
+//!
+//! assert_eq!(diagnostics.summary(), "1 error, 1 warning");
+//! # #[cfg(not(feature = "colorize"))]
+//! assert_eq!(
+//!     diagnostics.to_string(),
+//!     "\nerrors:\n[error] The host key is missing.\nwarnings:\n[warning] The timeout key is deprecated.\n1 error, 1 warning"
+//! );
+//! assert!(diagnostics.into_result().is_err());
+//! ```
+//!
+//! # Source Snippets
+//!
+//! The `source_code` option of a field marks it as the source code of the variant (any type that implements `AsRef<str>`),
+//! and the `label` option marks a field as a span of that source code (any type that implements [`SourceSpan`], such as a
+//! range of byte offsets). The error line is then followed by the labeled lines of the source code, with their line numbers
+//! and with carets under the spans:
+//!
+//! ```
+//! use mabe::Mabe;
+//! use std::ops::Range;
+//!
+//! #[derive(Mabe)]
+//! enum ParseError {
+//!     #[error("A semicolon is missing.")]
+//!     MissingSemicolon {
+//!         #[mabe(source_code)]
+//!         source: String,
+//!         #[mabe(label = "expected `;` here")]
+//!         span: Range<usize>,
+//!     },
+//! }
+//!
+//! let error = ParseError::MissingSemicolon { source: "let x = 5\nlet y = 6;".to_string(), span: 9..9 };
+//! assert!(error.to_string().ends_with("\n  |\n1 | let x = 5\n  |          ^ expected `;` here"));
+//! ```
+//!
+//! The snippets handle tabs, multi-byte characters, and spans that cover several lines, and the [`render_snippet`] function
+//! renders them outside of the generated code.
+//!
+//! # Chains of Causes
+//!
+//! The `source` option of a field marks it as the cause of the variant (any error, including `Box<dyn Error + Send + Sync>`),
+//! which is then returned by the `source` method of the [`Error`](core::error::Error) trait and by the
+//! [`mabe_source`](MabeError::mabe_source) method, which tells whether the cause derives [`Mabe`](derive@Mabe) too. The
+//! [`Report`] wrapper renders the whole chain of causes as an indented tree, with the error and debug messages of the causes
+//! that derive [`Mabe`](derive@Mabe) and the `Display` implementation of the foreign ones:
+//!
+//! ```
+//! use mabe::{Mabe, Report};
+//!
+//! #[derive(Mabe)]
+//! enum ConfigError {
+//!     #[error("The config file could not be read.")]
+//!     Unreadable(#[mabe(source)] std::io::Error),
+//! }
+//!
+//! #[derive(Mabe)]
+//! enum AppError {
+//!     #[error("The app could not start.")]
+//!     Startup(#[mabe(source)] ConfigError),
+//! }
+//!
+//! let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "no such file");
+//! let report = Report::new(AppError::Startup(ConfigError::Unreadable(io_error)));
+//! let lines = report.to_string().lines().map(str::to_string).collect::<Vec<_>>();
+//! assert!(lines[2].starts_with("  ") && lines[2].ends_with("The config file could not be read."));
+//! assert!(lines[3].starts_with("    ") && lines[3].ends_with("no such file"));
+//! ```
+//!
+//! The number of rendered causes can be limited with the [`max_depth`](Report::max_depth) method (16 by default), and the
+//! rendering stops if the chain loops back on itself.
+//!
+//! # Adding Context
+//!
+//! The [`ResultExt`] trait adds situational context to the errors of any `Result` without defining a variant for every call
+//! site. The [`mabe_context`](ResultExt::mabe_context) method wraps the error into a variant whose field has the `source`
+//! option, and the [`with_debug`](ResultExt::with_debug) method attaches a note that is rendered as an extra `[debug]` line
+//! (both by the `Display` implementation and in a [`Report`]):
+//!
+//! ```
+//! use mabe::{Mabe, ResultExt};
+//!
+//! #[derive(Mabe)]
+//! enum ConfigError {
+//!     #[error("The config file could not be read.")]
+//!     Unreadable(#[mabe(source)] std::io::Error),
+//! }
+//!
+//! let result = std::fs::read_to_string("missing.toml")
+//!     .mabe_context(ConfigError::Unreadable)
+//!     .with_debug(|| "The path is `missing.toml`.");
+//! assert!(result.unwrap_err().to_string().ends_with(" The path is `missing.toml`."));
+//! ```
+//!
+//! # Backtraces
+//!
+//! The `backtrace` option of a field marks it as the
+//! [`Backtrace`](https://doc.rust-lang.org/std/backtrace/struct.Backtrace.html) of the variant, which is returned by the
+//! generated `backtrace` method and rendered by the alternate `{:#}` form of the `Display` implementation. The `from` option
+//! of a field generates a `From` implementation that creates the variant from the field (which is then its source), and that
+//! captures the backtrace of the variant if the `RUST_BACKTRACE` (or `RUST_LIB_BACKTRACE`) variable is set:
+//!
+//! ```
+//! use mabe::Mabe;
+//! use std::backtrace::Backtrace;
+//!
+//! #[derive(Mabe)]
+//! enum StorageError {
+//!     #[error("The file could not be opened.")]
+//!     Open(#[mabe(from)] std::io::Error, #[mabe(backtrace)] Backtrace),
+//! }
+//!
+//! fn open() -> Result<String, StorageError> {
+//!     Ok(std::fs::read_to_string("missing.txt")?)
+//! }
+//!
+//! let error = open().unwrap_err();
+//! assert!(error.backtrace().is_some());
+//! println!("{:#}", error);
+//! ```
+//!
+//! Backtraces are not available in `no_std` environments, and they are left out of the `Debug` implementation (and therefore
+//! of the state of the variants).
+//!
+//! # Caller Locations
+//!
+//! The `location` option of a field marks it as the location where the variant was created (a
+//! `&'static Location<'static>`), which is recorded by the generated `From` implementations (i.e. at the `?` operator) and by
+//! the generated constructors (see [Constructors](#constructors)), which are `#[track_caller]`. A variant that has a location
+//! must therefore have a constructor or a `From` implementation (the same goes for the backtraces). The location is returned
+//! by the generated `location` method and rendered in an `[at]` line:
+//!
+//! ```
+//! use mabe::Mabe;
+//! use std::panic::Location;
+//!
+//! #[derive(Mabe)]
+//! enum DbError {
+//!     #[error("The query failed: {0}")]
+//!     QueryFailed(String, #[mabe(location)] &'static Location<'static>),
+//! }
+//!
+//! let error = DbError::query_failed("SELECT 1".to_string());
+//! assert_eq!(error.location().unwrap().line(), line!() - 1);
+//! assert!(error.to_string().ends_with(&error.location().unwrap().to_string())); // e.g. `[at] src/main.rs:8:13`
+//! ```
+//!
+//! # Constructors
+//!
+//! A constructor is generated for each variant, which is named after the variant in snake case (or as a raw identifier if
+//! the name is a keyword) and takes the fields of the variant as parameters, with `impl Into<T>` for the owned string-like
+//! fields (i.e. `String`, `Box<str>`, `Cow<str>`, `PathBuf`, and `OsString`). The fields that have the `backtrace` or
+//! `location` option are filled automatically. A constructor whose name is already used by a generated method (e.g. `error`
+//! for an `Error` variant) is a compile-time error that points to its variant, and the `no_constructor` option of a variant
+//! skips its constructor (e.g. if its name clashes with a generated method, or with a method of the enum that is written by
+//! hand):
+//!
+//! ```
+//! use mabe::Mabe;
+//!
+//! #[derive(Mabe)]
+//! enum ServerError {
+//!     #[error("The connection was lost: {cause}.")]
+//!     #[debug("Retrying in {retry_in} seconds.")]
+//!     ConnectionLost { cause: String, retry_in: u32 },
+//!
+//!     #[mabe(no_constructor)]
+//!     #[error("The request timed out.")]
+//!     Timeout,
+//! }
+//!
+//! impl ServerError {
+//!     pub fn timeout() -> Self {
+//!         Self::Timeout
+//!     }
+//! }
+//!
+//! let error = ServerError::connection_lost("timeout", 10);
+//! assert_eq!(error.error(), "The connection was lost: timeout.");
+//! ```
+//!
+//! Similarly, the `is_*` and `as_*` methods of each variant (e.g. `is_connection_lost` and `as_connection_lost`) tell whether
+//! an error is the variant and return references to its fields respectively (as a tuple, or as a single reference if the
+//! variant has only one field). An accessor whose name is already used is a compile-time error too, and the `no_accessors`
+//! option of a variant skips its accessors:
+//!
+//! ```
+//! # use mabe::Mabe;
+//! #
+//! # #[derive(Mabe)]
+//! # enum ServerError {
+//! #     #[error("The connection was lost: {cause}.")]
+//! #     #[debug("Retrying in {retry_in} seconds.")]
+//! #     ConnectionLost { cause: String, retry_in: u32 },
+//! # }
+//! #
+//! let error = ServerError::connection_lost("timeout", 10);
+//! assert!(error.is_connection_lost());
+//! assert_eq!(error.as_connection_lost(), Some((&"timeout".to_string(), &10)));
+//! ```
+//!
+//! # Conversions
+//!
+//! The unit variants can be rebuilt from strings and integers (e.g. for the errors received from config files, FFI, or remote
+//! services). The `from_str` option of an enum generates a `FromStr` implementation that matches the names and the codes of
+//! the unit variants, and its `try_from_code` option generates `TryFrom<u16>` and `TryFrom<i32>` implementations that match
+//! their integer codes, as well as the `as_code` method that returns the integer code of a variant. The integer code of a
+//! variant is its numeric `code` option, or its discriminant otherwise (which is only used if it is explicit, or if the
+//! `code_prefix` option is set), so it always agrees with its code. The conversions fail with a [`ConversionError`]:
+//!
+//! ```
+//! use mabe::Mabe;
+//!
+//! #[derive(Mabe)]
+//! #[mabe(from_str, try_from_code)]
+//! enum HttpError {
+//!     #[error("The resource was not found.")]
+//!     NotFound = 404,
+//!
+//!     #[error("The request timed out.")]
+//!     Timeout = 408,
+//! }
+//!
+//! assert!("NotFound".parse::<HttpError>().unwrap().is_not_found());
+//! assert!(HttpError::try_from(408_u16).unwrap().is_timeout());
+//! assert!(HttpError::try_from(418_u16).is_err());
+//! assert_eq!(HttpError::NotFound.as_code(), Some(404));
+//! ```
+//!
 //! # Cargo Features
 //!
 //! The following is a list of
 //! [Cargo features](https://doc.rust-lang.org/stable/cargo/reference/features.html#the-features-section) that can be enabled or
 //! disabled in the `Cargo.toml` file:
 //!
-//! * **colorize**: Adds colors to the prefixes of the error and debug messages (i.e. to `[error]` and `[debug]`) when they are
-//!   printed. This feature only works with ANSI-compatible terminals.
+//! * **colorize**: Adds colors to the prefixes of the error and debug messages (i.e. to `[error]` and `[debug]`, as well as to
+//!   the labels of the other severities, to `[see]`, to `[at]`, to `[cause]`, and to `[backtrace]`) when they are printed. This
+//!   feature only works with ANSI-compatible terminals.
+//! * **alloc** (enabled by default): Adds the items that allocate (i.e. the `state`, `error`, and `debug` methods of the
+//!   [`MabeError`] trait, as well as the context wrappers, the conversions, the `Diagnostics` collection, and the `Report`
+//!   wrapper). Without it, the crate only depends on the `core` crate, and the enums must set the `no_alloc` option.
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+mod cause;
+#[cfg(feature = "alloc")]
+mod context;
+#[cfg(feature = "alloc")]
+mod conversion;
+#[cfg(feature = "alloc")]
+mod diagnostics;
+#[cfg(feature = "alloc")]
+mod report;
+mod snippet;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[doc(hidden)]
+pub use cause::private as __private;
+pub use cause::Cause;
+#[cfg(feature = "alloc")]
+pub use context::{Context, ResultExt};
+#[cfg(feature = "alloc")]
+pub use conversion::ConversionError;
+use core::any::Any;
+use core::error::Error;
+use core::fmt;
+#[cfg(feature = "alloc")]
+pub use diagnostics::Diagnostics;
+#[cfg(feature = "alloc")]
+pub use report::Report;
+pub use snippet::{render_snippet, SourceSpan};
+
+pub use mabe_derive::Mabe;
+
+/// The severity of a variant of an enum that derives [`Mabe`](derive@Mabe), as set by the `severity` option of the variant or
+/// of the enum. The severities are ordered from the least to the most severe, and their label is used as the prefix of the
+/// error line (e.g. `[warning]`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// A note about a noteworthy situation.
+    Note,
+    /// A warning about a potential problem.
+    Warning,
+    /// An error from which the program can recover. This is the default severity.
+    #[default]
+    Error,
+    /// An error from which the program cannot recover.
+    Fatal,
+}
+
+impl Severity {
+    /// Returns the label of the severity (e.g. `"warning"`).
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Note => "note",
+            Self::Warning => "warning",
+            Self::Error => "error",
+            Self::Fatal => "fatal",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
-extern crate proc_macro;
-mod api;
-mod error;
-use api::mabe;
+/// The metadata of a variant of an enum that derives [`Mabe`](derive@Mabe), which is available in `const` contexts through the
+/// `VARIANTS` constant and the `variant_info` method of the enum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VariantInfo {
+    name: &'static str,
+    code: &'static str,
+    severity: Severity,
+    error_template: &'static str,
+    debug_template: &'static str,
+    error_placeholders: &'static [&'static str],
+    debug_placeholders: &'static [&'static str],
+    explanation: &'static str,
+    help_url: &'static str,
+}
+
+impl VariantInfo {
+    /// Creates the metadata of a variant that has no code, no explanation, and no help URL, and whose severity is the default
+    /// one (the generated code sets the other fields with the hidden builder methods below).
+    pub(crate) const fn new(
+        name: &'static str,
+        error_template: &'static str,
+        debug_template: &'static str,
+        error_placeholders: &'static [&'static str],
+        debug_placeholders: &'static [&'static str],
+    ) -> Self {
+        Self {
+            name,
+            code: "",
+            severity: Severity::Error,
+            error_template,
+            debug_template,
+            error_placeholders,
+            debug_placeholders,
+            explanation: "",
+            help_url: "",
+        }
+    }
+
+    #[doc(hidden)]
+    pub const fn __with_code(self, code: &'static str) -> Self {
+        Self { code, ..self }
+    }
+
+    #[doc(hidden)]
+    pub const fn __with_severity(self, severity: Severity) -> Self {
+        Self { severity, ..self }
+    }
+
+    #[doc(hidden)]
+    pub const fn __with_explanation(self, explanation: &'static str) -> Self {
+        Self { explanation, ..self }
+    }
+
+    #[doc(hidden)]
+    pub const fn __with_help_url(self, help_url: &'static str) -> Self {
+        Self { help_url, ..self }
+    }
+
+    /// Returns the metadata of the variant that has the given code among the given variants (e.g. the `VARIANTS` constant of an
+    /// enum), or `None` if no variant has this code.
+    pub fn find_by_code(variants: &'static [VariantInfo], code: &str) -> Option<&'static VariantInfo> {
+        variants.iter().find(|info| info.code() == Some(code))
+    }
+
+    /// Returns the name of the variant.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the code of the variant, or `None` if the variant doesn't have one.
+    pub const fn code(&self) -> Option<&'static str> {
+        match self.code.is_empty() {
+            true => None,
+            false => Some(self.code),
+        }
+    }
+
+    /// Returns the severity of the variant.
+    pub const fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns the error message of the variant as it is written in its `error` attribute (i.e. with its placeholders).
+    pub const fn error_template(&self) -> &'static str {
+        self.error_template
+    }
+
+    /// Returns the debug message of the variant as it is written in its `debug` attribute (i.e. with its placeholders), or an
+    /// empty string if the variant doesn't have a debug message.
+    pub const fn debug_template(&self) -> &'static str {
+        self.debug_template
+    }
+
+    /// Returns the names (or indices) of the fields that are interpolated in the error message, in the order of their first
+    /// appearance.
+    pub const fn error_placeholders(&self) -> &'static [&'static str] {
+        self.error_placeholders
+    }
+
+    /// Returns the names (or indices) of the fields that are interpolated in the debug message, in the order of their first
+    /// appearance.
+    pub const fn debug_placeholders(&self) -> &'static [&'static str] {
+        self.debug_placeholders
+    }
+
+    /// Returns the long-form explanation of the variant, as set by its `explain` attribute, or `None` if the variant doesn't
+    /// have one.
+    pub const fn explanation(&self) -> Option<&'static str> {
+        match self.explanation.is_empty() {
+            true => None,
+            false => Some(self.explanation),
+        }
+    }
+
+    /// Returns the help URL of the variant, as set by its `url` option or by the `docs_base` option of the enum, or `None` if
+    /// the variant doesn't have one.
+    pub const fn help_url(&self) -> Option<&'static str> {
+        match self.help_url.is_empty() {
+            true => None,
+            false => Some(self.help_url),
+        }
+    }
+}
+
+/// The trait implemented by every enum that derives [`Mabe`](derive@Mabe) (unless the `no_alloc` option is set). It exposes the
+/// methods generated by the derive macro so that generic code can consume any error enum, and it is object-safe so that
+/// errors of different enums can be stored as `dyn MabeError` and downcast back to their concrete type. The `state`, `error`,
+/// and `debug` methods are only available with the `alloc` feature.
+pub trait MabeError: Error + Any {
+    /// Returns the metadata of the variant.
+    fn variant_info(&self) -> &'static VariantInfo;
+
+    /// Returns the name of the variant.
+    fn variant_name(&self) -> &'static str;
 
-#[cfg(debug_assertions)]
-mod utils;
+    /// Returns the code of the variant, or `None` if the variant doesn't have one.
+    fn code(&self) -> Option<&'static str> {
+        self.variant_info().code()
+    }
 
-/// The derive macro that creates the debug-friendly error enums. It provides an `error` and a `debug` attribute for each
-/// variant of the enum, which can be used to define the error and debug messages respectively. The macro also automatically
-/// generates implementations for the [`Debug`](std::fmt::Debug), [`Display`](std::fmt::Display), and
-/// [`Error`](std::error::Error) traits.
-#[proc_macro_derive(Mabe, attributes(error, debug))]
-pub fn mabe_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    mabe(input)
+    /// Returns the severity of the variant.
+    fn severity(&self) -> Severity {
+        self.variant_info().severity()
+    }
+
+    /// Returns the long-form explanation of the variant, or `None` if the variant doesn't have one.
+    fn explain(&self) -> Option<&'static str> {
+        self.variant_info().explanation()
+    }
+
+    /// Returns the help URL of the variant, or `None` if the variant doesn't have one.
+    fn help_url(&self) -> Option<&'static str> {
+        self.variant_info().help_url()
+    }
+
+    /// Returns the cause of the error (i.e. the field of the variant that has the `source` option), or `None` if the variant
+    /// doesn't have one.
+    fn mabe_source(&self) -> Option<Cause<'_>> {
+        None
+    }
+
+    /// Writes the debug notes attached to the error (e.g. by the [`with_debug`](ResultExt::with_debug) method) to the given
+    /// writer, each on a new line prefixed with `[debug]`, which is how they are rendered after the messages of the error.
+    fn write_notes(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        let _ = w;
+        Ok(())
+    }
+
+    /// Returns the state of the error (i.e. the name of the enum and the variant, followed by the values of its fields).
+    #[cfg(feature = "alloc")]
+    fn state(&self) -> String;
+
+    /// Returns the error message of the variant.
+    #[cfg(feature = "alloc")]
+    fn error(&self) -> String;
+
+    /// Returns the debug message of the variant.
+    #[cfg(feature = "alloc")]
+    fn debug(&self) -> String;
+
+    /// Writes the error message of the variant to the given writer.
+    fn write_error(&self, w: &mut dyn fmt::Write) -> fmt::Result;
+
+    /// Writes the debug message of the variant to the given writer.
+    fn write_debug(&self, w: &mut dyn fmt::Write) -> fmt::Result;
+
+    /// Returns the error message of the variant if it does not depend on any field.
+    fn error_str(&self) -> Option<&'static str>;
+
+    /// Returns the debug message of the variant if it does not depend on any field.
+    fn debug_str(&self) -> Option<&'static str>;
+
+    /// Returns the error as [`Any`], so that it can be downcast to its concrete type.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns the error as a mutable [`Any`], so that it can be downcast to its concrete type.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Returns the error as a standard error (e.g. to be returned by the `source` method of another error).
+    fn as_error(&self) -> &(dyn Error + 'static);
 }
+
+macro_rules! impl_downcast {
+    ($($bounds:tt)*) => {
+        impl dyn MabeError $($bounds)* {
+            /// Returns `true` if the error is of type `E`.
+            pub fn is<E: MabeError>(&self) -> bool {
+                self.as_any().is::<E>()
+            }
+
+            /// Returns a reference to the error if it is of type `E`, or `None` if it is not.
+            pub fn downcast_ref<E: MabeError>(&self) -> Option<&E> {
+                self.as_any().downcast_ref::<E>()
+            }
+
+            /// Returns a mutable reference to the error if it is of type `E`, or `None` if it is not.
+            pub fn downcast_mut<E: MabeError>(&mut self) -> Option<&mut E> {
+                self.as_any_mut().downcast_mut::<E>()
+            }
+        }
+    };
+}
+
+impl_downcast!();
+impl_downcast!(+ Send);
+impl_downcast!(+ Send + Sync);
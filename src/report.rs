@@ -0,0 +1,131 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use crate::__private::PrefixedWriter;
+use crate::{Cause, MabeError};
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+use core::ptr;
+
+/// The default maximum number of causes rendered by a [`Report`].
+const DEFAULT_MAX_DEPTH: usize = 16;
+
+/// The prefixes of the lines of the causes rendered by a [`Report`], which are also used by the context wrappers.
+#[cfg(not(feature = "colorize"))]
+pub(crate) const PREFIXES: (&str, &str) = ("[cause]", "[debug]");
+#[cfg(feature = "colorize")]
+pub(crate) const PREFIXES: (&str, &str) = ("\u{1b}[1;31m[cause]\u{1b}[0m", "\u{1b}[1;32m[debug]\u{1b}[0m");
+
+/// A wrapper that renders an error that derives [`Mabe`](derive@crate::Mabe) together with the whole chain of its causes, as an
+/// indented tree. Each cause is rendered under a `[cause]` heading, with the error and debug messages (and the notes) of the
+/// causes that derive [`Mabe`](derive@crate::Mabe) and the [`Display`](fmt::Display) implementation of the foreign ones. The
+/// number of rendered causes is limited (16 by default), and the rendering stops if the chain loops back on itself.
+pub struct Report<E> {
+    error: E,
+    max_depth: usize,
+}
+
+impl<E: MabeError> Report<E> {
+    /// Creates a report for the given error.
+    pub fn new(error: E) -> Self {
+        Self { error, max_depth: DEFAULT_MAX_DEPTH }
+    }
+
+    /// Sets the maximum number of causes that are rendered.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Returns a reference to the reported error.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Returns the reported error.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E: MabeError> From<E> for Report<E> {
+    fn from(error: E) -> Self {
+        Self::new(error)
+    }
+}
+
+impl<E: MabeError> fmt::Debug for Report<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.error, f)
+    }
+}
+
+impl<E: MabeError> fmt::Display for Report<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)?;
+
+        let mut visited = Vec::from([&self.error as &(dyn Error + 'static) as *const (dyn Error + 'static)]);
+        let mut cause = self.error.mabe_source();
+        let mut depth = 1;
+
+        // The errors are identified by their address and their type (i.e. their vtable), since an error and its first field
+        // can share the same address. A type can have several vtables, so a loop may only be detected after a repetition, and
+        // the depth limit stops the rendering in the worst case.
+
+        while let Some(current) = cause {
+            let mut w = Indented { f, indent: depth * 2 };
+            fmt::Write::write_str(&mut w, "\n")?;
+
+            if depth > self.max_depth {
+                return fmt::Write::write_fmt(&mut w, format_args!("{} ... (the remaining causes are omitted)", PREFIXES.0));
+            }
+
+            if visited.iter().any(|&error| ptr::eq(error, current.as_error())) {
+                return fmt::Write::write_fmt(&mut w, format_args!("{} ... (the chain of causes loops back)", PREFIXES.0));
+            }
+
+            fmt::Write::write_fmt(&mut w, format_args!("{} ", PREFIXES.0))?;
+            match current {
+                Cause::Mabe(error) => {
+                    error.write_error(&mut w)?;
+                    error.write_debug(&mut PrefixedWriter::new(&mut w, format_args!("\n{} ", PREFIXES.1)))?;
+                    error.write_notes(&mut w)?;
+                }
+                Cause::Foreign(error) => fmt::Write::write_fmt(&mut w, format_args!("{}", error))?,
+            }
+
+            visited.push(current.as_error());
+            cause = current.next();
+            depth += 1;
+        }
+
+        Ok(())
+    }
+}
+
+impl<E: MabeError> Error for Report<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// A writer that indents every line that it writes after the first one.
+struct Indented<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    indent: usize,
+}
+
+impl fmt::Write for Indented<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for (i, line) in s.split('\n').enumerate() {
+            if i > 0 {
+                self.f.write_fmt(format_args!("\n{:indent$}", "", indent = self.indent))?;
+            }
+
+            self.f.write_str(line)?;
+        }
+
+        Ok(())
+    }
+}
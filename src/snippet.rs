@@ -0,0 +1,118 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use core::fmt;
+use core::ops::{Range, RangeInclusive};
+
+/// The number of columns that a tab character takes when a source line is rendered.
+const TAB_WIDTH: usize = 4;
+
+/// A span of source code, which can be used as the type of a field that has the `label` option. The span is a range of byte
+/// offsets into the source code of the variant (i.e. into its field that has the `source_code` option).
+pub trait SourceSpan {
+    /// Returns the range of byte offsets of the span.
+    fn byte_range(&self) -> Range<usize>;
+}
+
+impl SourceSpan for Range<usize> {
+    fn byte_range(&self) -> Range<usize> {
+        self.clone()
+    }
+}
+
+impl SourceSpan for RangeInclusive<usize> {
+    fn byte_range(&self) -> Range<usize> {
+        *self.start()..self.end().saturating_add(1)
+    }
+}
+
+impl<T: SourceSpan + ?Sized> SourceSpan for &T {
+    fn byte_range(&self) -> Range<usize> {
+        (**self).byte_range()
+    }
+}
+
+/// Renders the lines of the source code that are covered by the labels, with their line numbers and with carets (`^`) under
+/// the labeled spans, followed by the text of each label on the last line of its span. Each rendered line starts with a line
+/// break. The spans are clamped to the source code and to the boundaries of its characters, tabs are expanded, and the columns
+/// are counted in characters (not in bytes), so that the carets are aligned with the rendered lines.
+pub fn render_snippet(w: &mut dyn fmt::Write, source: &str, labels: &[(Range<usize>, &str)]) -> fmt::Result {
+    // The lines of the source code, as ranges of byte offsets that don't include the line breaks.
+    let lines = || {
+        source.split('\n').scan(0, |start, line| {
+            let range = *start..*start + line.len();
+            *start += line.len() + 1;
+            Some(range)
+        })
+    };
+
+    // The spans of the labels, clamped to the source code and to the boundaries of its characters.
+    let clamp = |offset: usize| {
+        let mut offset = offset.min(source.len());
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        offset
+    };
+    let spans = || labels.iter().map(|(span, text)| (clamp(span.start)..clamp(span.end.max(span.start)), *text));
+
+    // A line is covered by a span if they overlap, or if the span is empty and starts on the line.
+    let covers = |line: &Range<usize>, span: &Range<usize>| {
+        span.start <= line.end && (span.end > line.start || span.start >= line.start)
+    };
+
+    let last_line = lines()
+        .enumerate()
+        .filter(|(_, line)| spans().any(|(span, _)| covers(line, &span)))
+        .map(|(number, _)| number + 1)
+        .last();
+    let gutter = match last_line {
+        Some(number) => number.ilog10() as usize + 1,
+        None => return Ok(()),
+    };
+
+    w.write_fmt(format_args!("\n{:gutter$} |", ""))?;
+    let mut previous = None;
+
+    for (index, line) in lines().enumerate().filter(|(_, line)| spans().any(|(span, _)| covers(line, &span))) {
+        if previous.is_some_and(|previous| index > previous + 1) {
+            w.write_str("\n...")?;
+        }
+        previous = Some(index);
+
+        let text = &source[line.clone()];
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        w.write_fmt(format_args!("\n{:>gutter$} | ", index + 1))?;
+        for c in text.chars() {
+            match c {
+                '\t' => w.write_fmt(format_args!("{:TAB_WIDTH$}", ""))?,
+                c => w.write_char(c)?,
+            }
+        }
+
+        for (span, label) in spans().filter(|(span, _)| covers(&line, span)) {
+            // The columns of the span on the line, which are at least one column apart so that empty spans get a caret.
+            let start = column(text, span.start.max(line.start) - line.start);
+            let end = column(text, span.end.min(line.start + text.len()) - line.start).max(start + 1);
+
+            w.write_fmt(format_args!("\n{:gutter$} | {:start$}", "", ""))?;
+            for _ in start..end {
+                w.write_char('^')?;
+            }
+
+            if span.end <= line.end + 1 && !label.is_empty() {
+                w.write_fmt(format_args!(" {}", label))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the column of a byte offset in a line, counted in characters (with tabs expanded).
+fn column(text: &str, offset: usize) -> usize {
+    text.char_indices()
+        .take_while(|(index, _)| *index < offset)
+        .map(|(_, c)| if c == '\t' { TAB_WIDTH } else { 1 })
+        .sum::<usize>()
+}
@@ -0,0 +1,59 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::Mabe;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::error::Error;
+
+#[derive(Mabe)]
+enum StorageError {
+    #[error("The file could not be opened.")]
+    Open(#[mabe(from)] std::io::Error, #[mabe(backtrace)] Backtrace),
+
+    #[error("The file is not valid UTF-8.")]
+    Encoding {
+        #[mabe(from)]
+        source: std::string::FromUtf8Error,
+    },
+
+    #[error("The file is locked by {0}.")]
+    Locked(String, #[mabe(backtrace)] Backtrace),
+}
+
+fn open() -> Result<(), StorageError> {
+    Err(std::io::Error::new(std::io::ErrorKind::NotFound, "no such file"))?;
+    Ok(())
+}
+
+#[test]
+fn test() {
+    // The `From` implementations keep the original error as the source, and capture the backtrace of the variant.
+    let error = open().unwrap_err();
+    assert_eq!(error.source().unwrap().to_string(), "no such file");
+    assert!(error.backtrace().is_some());
+    assert!(StorageError::from(String::from_utf8(vec![0xff]).unwrap_err()).backtrace().is_none());
+
+    // The backtraces are left out of the state of the variants.
+    let error = StorageError::Locked("another process".to_string(), Backtrace::force_capture());
+    assert_eq!(error.state(), "StorageError::Locked(\"another process\", <backtrace>)");
+    assert_eq!(error.backtrace().unwrap().status(), BacktraceStatus::Captured);
+
+    // The backtraces are only rendered by the alternate form of the `Display` implementation.
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(error.to_string(), "\n[error] The file is locked by another process.");
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(error.to_string(), "\n\u{1b}[1;31m[error]\u{1b}[0m The file is locked by another process.");
+
+    #[cfg(not(feature = "colorize"))]
+    assert!(format!("{:#}", error).starts_with("\n[error] The file is locked by another process.\n[backtrace]\n"));
+
+    #[cfg(feature = "colorize")]
+    assert!(format!("{:#}", error).starts_with(
+        "\n\u{1b}[1;31m[error]\u{1b}[0m The file is locked by another process.\n\u{1b}[1;37m[backtrace]\u{1b}[0m\n"
+    ));
+
+    // The backtraces that are not captured are not rendered.
+    let error = StorageError::Locked("another process".to_string(), Backtrace::disabled());
+    assert_eq!(format!("{:#}", error), error.to_string());
+}
@@ -0,0 +1,54 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::Mabe;
+
+// The configuration predicates are always true (`test`) or always false (`not(test)`), so that the test doesn't depend on the
+// enabled features.
+#[derive(Mabe)]
+enum Error {
+    /// A variant that is always compiled, with a doc comment.
+    #[error("The error message for Cfg1.")]
+    Cfg1,
+
+    #[cfg(test)]
+    #[error("The error message for Cfg2. The value is {value}.")]
+    #[debug("The debug message for Cfg2.")]
+    Cfg2 { value: u32 },
+
+    #[cfg(not(test))]
+    #[error("The error message for Cfg3. The value is {0}.")]
+    Cfg3(NeverCompiled),
+
+    #[cfg_attr(test, allow(dead_code))]
+    #[deprecated = "Use `Cfg1` instead."]
+    #[error("The error message for Cfg4.")]
+    Cfg4,
+}
+
+#[test]
+fn test() {
+    // The variants whose configuration predicate is false are removed before the derive macro runs.
+    assert_eq!(Error::VARIANT_NAMES, ["Cfg1", "Cfg2", "Cfg4"]);
+
+    let error1 = Error::Cfg1;
+    assert_eq!(error1.state(), "Error::Cfg1");
+    assert_eq!(error1.error(), "The error message for Cfg1.");
+    assert_eq!(error1.debug(), "");
+
+    println!("{}", error1);
+
+    let error2 = Error::Cfg2 { value: 7 };
+    assert_eq!(error2.state(), "Error::Cfg2 { value: 7 }");
+    assert_eq!(error2.error(), "The error message for Cfg2. The value is 7.");
+    assert_eq!(error2.debug(), "The debug message for Cfg2.");
+
+    println!("{}", error2);
+
+    #[allow(deprecated)]
+    let error4 = Error::Cfg4;
+    assert_eq!(error4.state(), "Error::Cfg4");
+    assert_eq!(error4.error(), "The error message for Cfg4.");
+
+    println!("{}", error4);
+}
@@ -0,0 +1,63 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::Mabe;
+use std::borrow::Cow;
+use std::path::PathBuf;
+
+#[derive(Mabe)]
+enum ServerError {
+    #[error("The connection was lost: {cause}.")]
+    #[debug("Retrying in {retry_in} seconds.")]
+    ConnectionLost { cause: String, retry_in: u32 },
+
+    #[error("The file {0:?} could not be served: {1}.")]
+    FileNotServed(PathBuf, Cow<'static, str>),
+
+    #[error("The server is shutting down.")]
+    ShuttingDown,
+
+    #[error("The request has an invalid type: {0}.")]
+    Type(Box<str>),
+
+    // The name of the constructor would clash with the `error` method.
+    #[mabe(no_constructor)]
+    #[error("An unknown error occurred.")]
+    Error,
+
+    // The name of the constructor is only used by a generated method with the `kind` option.
+    #[error("The request is of an unknown kind.")]
+    Kind,
+
+    // The name of the constructor would clash with a method that is written by hand.
+    #[mabe(no_constructor)]
+    #[error("The request timed out after {0} seconds.")]
+    Timeout(u32),
+}
+
+impl ServerError {
+    fn timeout() -> Self {
+        Self::Timeout(30)
+    }
+}
+
+#[test]
+fn test() {
+    let error = ServerError::connection_lost("timeout", 10);
+    assert_eq!(error.state(), "ServerError::ConnectionLost { cause: \"timeout\", retry_in: 10 }");
+
+    let error = ServerError::connection_lost(String::from("reset by peer"), 5);
+    assert_eq!(error.error(), "The connection was lost: reset by peer.");
+
+    let error = ServerError::file_not_served("index.html", "permission denied");
+    assert_eq!(error.error(), "The file \"index.html\" could not be served: permission denied.");
+
+    assert_eq!(ServerError::shutting_down().variant_name(), "ShuttingDown");
+
+    // The names that are keywords are raw identifiers.
+    assert_eq!(ServerError::r#type("json").error(), "The request has an invalid type: json.");
+
+    assert_eq!(ServerError::Error.variant_name(), "Error");
+    assert_eq!(ServerError::kind().variant_name(), "Kind");
+    assert_eq!(ServerError::timeout().error(), "The request timed out after 30 seconds.");
+}
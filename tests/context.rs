@@ -0,0 +1,122 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::{Cause, Context, Mabe, MabeError, Report, ResultExt};
+use std::error::Error;
+
+#[derive(Mabe)]
+enum ConfigError {
+    #[error("The config file could not be read.")]
+    #[debug("Check the permissions of the config file.")]
+    Unreadable(#[mabe(source)] std::io::Error),
+
+    #[error("The config file is empty.")]
+    Empty,
+}
+
+#[derive(Mabe)]
+enum AppError {
+    #[error("The app could not start.")]
+    Startup(#[mabe(source)] Context<ConfigError>),
+
+    #[error("The app could not reload.")]
+    Reload(#[mabe(source)] Context<Context<ConfigError>>),
+
+    #[error("A plugin failed.")]
+    Plugin(#[mabe(source)] Box<dyn Error + Send + Sync>),
+}
+
+fn read_config(path: &str) -> Result<String, ConfigError> {
+    Err(std::io::Error::new(std::io::ErrorKind::NotFound, "no such file")).mabe_context(ConfigError::Unreadable)?;
+    Ok(path.to_string())
+}
+
+#[test]
+fn test() {
+    // The foreign errors are wrapped into variants, and kept as their sources.
+    let error = read_config("app.toml").unwrap_err();
+    assert_eq!(error.variant_name(), "Unreadable");
+    assert_eq!(error.source().unwrap().to_string(), "no such file");
+
+    // The notes are only computed for errors, and rendered as extra debug lines.
+    let ok = Ok::<_, ConfigError>(1).with_debug(|| -> String { panic!("The note is computed for an Ok value.") });
+    assert_eq!(ok.unwrap(), 1);
+
+    let error = Err::<(), _>(ConfigError::Empty).with_debug(|| "The path is `app.toml`.").unwrap_err();
+    assert_eq!(error.note(), "The path is `app.toml`.");
+    assert_eq!(error.variant_name(), "Empty");
+    assert_eq!(error.debug(), "");
+    assert_eq!(error.debug_str(), Some(""));
+
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(error.to_string(), "\n[error] The config file is empty.\n[debug] The path is `app.toml`.");
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(
+        error.to_string(),
+        "\n\u{1b}[1;31m[error]\u{1b}[0m The config file is empty.\n\u{1b}[1;32m[debug]\u{1b}[0m The path is `app.toml`."
+    );
+
+    // The notes can be nested, and are rendered after the debug message of the error.
+    let error = read_config("app.toml")
+        .with_debug(|| format!("The path is `{}`.", "app.toml"))
+        .mabe_context(AppError::Startup)
+        .unwrap_err();
+    assert!(matches!(error.mabe_source(), Some(Cause::Mabe(cause)) if cause.variant_name() == "Unreadable"));
+    assert_eq!(error.source().unwrap().source().unwrap().to_string(), "no such file");
+
+    let nested = Err::<(), _>(ConfigError::Empty).with_debug(|| "The first note.").with_debug(|| "The second note.");
+    let nested = nested.unwrap_err();
+    let mut notes = String::new();
+    nested.write_notes(&mut notes).unwrap();
+
+    #[cfg(not(feature = "colorize"))]
+    {
+        assert_eq!(notes, "\n[debug] The first note.\n[debug] The second note.");
+        assert_eq!(
+            nested.to_string(),
+            "\n[error] The config file is empty.\n[debug] The first note.\n[debug] The second note."
+        );
+        assert_eq!(
+            Report::new(error).to_string(),
+            "\n[error] The app could not start.\n  [cause] The config file could not be read.\n  [debug] Check the permissions of the config file.\n  [debug] The path is `app.toml`.\n    [cause] no such file"
+        );
+    }
+
+    #[cfg(feature = "colorize")]
+    {
+        assert_eq!(notes, "\n\u{1b}[1;32m[debug]\u{1b}[0m The first note.\n\u{1b}[1;32m[debug]\u{1b}[0m The second note.");
+        assert_eq!(
+            nested.to_string(),
+            "\n\u{1b}[1;31m[error]\u{1b}[0m The config file is empty.\n\u{1b}[1;32m[debug]\u{1b}[0m The first note.\n\u{1b}[1;32m[debug]\u{1b}[0m The second note."
+        );
+        assert_eq!(
+            Report::new(error).to_string(),
+            "\n\u{1b}[1;31m[error]\u{1b}[0m The app could not start.\n  \u{1b}[1;31m[cause]\u{1b}[0m The config file could not be read.\n  \u{1b}[1;32m[debug]\u{1b}[0m Check the permissions of the config file.\n  \u{1b}[1;32m[debug]\u{1b}[0m The path is `app.toml`.\n    \u{1b}[1;31m[cause]\u{1b}[0m no such file"
+        );
+    }
+
+    // A report renders the notes of nested wrappers like their `Display` implementation.
+    let reload = AppError::Reload(nested);
+
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(
+        Report::new(reload).to_string(),
+        "\n[error] The app could not reload.\n  [cause] The config file is empty.\n  [debug] The first note.\n  [debug] The second note."
+    );
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(
+        Report::new(reload).to_string(),
+        "\n\u{1b}[1;31m[error]\u{1b}[0m The app could not reload.\n  \u{1b}[1;31m[cause]\u{1b}[0m The config file is empty.\n  \u{1b}[1;32m[debug]\u{1b}[0m The first note.\n  \u{1b}[1;32m[debug]\u{1b}[0m The second note."
+    );
+
+    // The boxed errors, which don't implement `Error`, can also be given context.
+    let boxed = Err::<(), Box<dyn Error + Send + Sync>>("the plugin crashed".into());
+    let context = boxed.with_debug(|| "The plugin is `auth`.").unwrap_err();
+    assert_eq!(context.error().to_string(), "the plugin crashed");
+
+    let error =
+        Err::<(), Box<dyn Error + Send + Sync>>("the plugin crashed".into()).mabe_context(AppError::Plugin).unwrap_err();
+    assert_eq!(error.source().unwrap().to_string(), "the plugin crashed");
+}
@@ -0,0 +1,131 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::{ConversionError, Mabe};
+
+#[derive(Mabe)]
+#[mabe(code_prefix = "HTTP-", code_width = 3, from_str, try_from_code)]
+enum HttpError {
+    #[error("The resource was not found.")]
+    NotFound = 404,
+
+    #[error("The request timed out.")]
+    Timeout = 408,
+
+    #[error("The server failed.")]
+    Internal = 500,
+}
+
+#[derive(Mabe)]
+#[mabe(from_str, try_from_code)]
+enum FfiError {
+    #[mabe(code = 1)]
+    #[error("The handle is invalid.")]
+    InvalidHandle,
+
+    #[mabe(code = 70000)]
+    #[error("The buffer is too small.")]
+    BufferTooSmall,
+
+    #[error("The call failed: {0}.")]
+    CallFailed(String),
+}
+
+#[derive(Mabe)]
+#[mabe(try_from_code)]
+#[repr(i32)]
+enum SignedError {
+    #[error("The value is negative.")]
+    Negative = -1,
+}
+
+// The codes and the integer codes of the variants follow the same numbering: the numeric `code` option, or the discriminant.
+#[derive(Mabe)]
+#[mabe(code_prefix = "DB-", from_str, try_from_code)]
+enum DbError {
+    #[error("The table is locked.")]
+    Locked = 10,
+
+    #[error("A deadlock was detected.")]
+    Deadlock,
+
+    #[mabe(code = 42)]
+    #[error("The table is corrupted.")]
+    Corrupted = 3,
+}
+
+// The conversions are opt-in, so they don't conflict with the implementations that are written by hand.
+#[derive(Mabe)]
+enum ParseError {
+    #[error("The input is empty.")]
+    Empty = 1,
+}
+
+impl std::str::FromStr for ParseError {
+    type Err = ();
+
+    fn from_str(_: &str) -> Result<Self, Self::Err> {
+        Ok(Self::Empty)
+    }
+}
+
+#[test]
+fn test() {
+    // The unit variants are parsed from their names and codes.
+    assert!("NotFound".parse::<HttpError>().unwrap().is_not_found());
+    assert!("HTTP-408".parse::<HttpError>().unwrap().is_timeout());
+    assert!("InvalidHandle".parse::<FfiError>().unwrap().is_invalid_handle());
+
+    let error = "CallFailed".parse::<FfiError>().unwrap_err();
+    assert!(error.is_unknown_name());
+    assert_eq!(error.error(), "`CallFailed` is not the name or the code of a unit variant of the `FfiError` enum.");
+
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(
+        "Gone".parse::<HttpError>().unwrap_err().to_string(),
+        "\n[error] `Gone` is not the name or the code of a unit variant of the `HttpError` enum."
+    );
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(
+        "Gone".parse::<HttpError>().unwrap_err().to_string(),
+        "\n\u{1b}[1;31m[error]\u{1b}[0m `Gone` is not the name or the code of a unit variant of the `HttpError` enum."
+    );
+
+    // The unit variants are converted from their explicit discriminants or numeric codes.
+    assert!(HttpError::try_from(500_u16).unwrap().is_internal());
+    assert!(HttpError::try_from(404_i32).unwrap().is_not_found());
+    assert!(FfiError::try_from(1_u16).unwrap().is_invalid_handle());
+    assert!(SignedError::try_from(-1_i32).unwrap().is_negative());
+
+    // The integer codes that don't fit in the type cannot be converted.
+    assert!(FfiError::try_from(70000_i32).unwrap().is_buffer_too_small());
+    assert!(matches!(FfiError::try_from(4464_u16), Err(ConversionError::UnknownCode { enum_name: "FfiError", code: 4464 })));
+    assert!(SignedError::try_from(65535_u16).is_err());
+
+    let error = HttpError::try_from(418_u16).unwrap_err();
+    assert_eq!(error.error(), "`418` is not the integer code of a unit variant of the `HttpError` enum.");
+
+    // The integer codes of the variants are returned the other way.
+    assert_eq!(HttpError::Timeout.as_code(), Some(408));
+    assert_eq!(FfiError::BufferTooSmall.as_code(), Some(70000));
+    assert_eq!(FfiError::call_failed("timeout").as_code(), None);
+    assert_eq!(SignedError::Negative.as_code(), Some(-1));
+
+    for error in [HttpError::NotFound, HttpError::Timeout, HttpError::Internal] {
+        let code = u16::try_from(error.as_code().unwrap()).unwrap();
+        assert_eq!(HttpError::try_from(code).unwrap().variant_name(), error.variant_name());
+    }
+
+    // The implicit discriminants are numbered after the previous variant, and the numeric codes take precedence.
+    assert_eq!(DbError::Deadlock.code(), Some("DB-0011"));
+    assert_eq!(DbError::Deadlock.as_code(), Some(11));
+    assert!(DbError::try_from(11_u16).unwrap().is_deadlock());
+    assert!("DB-0011".parse::<DbError>().unwrap().is_deadlock());
+    assert_eq!(DbError::Corrupted.code(), Some("DB-0042"));
+    assert_eq!(DbError::Corrupted.as_code(), Some(42));
+    assert!(DbError::try_from(42_u16).unwrap().is_corrupted());
+    assert!(DbError::try_from(3_u16).is_err());
+
+    assert_eq!("Full".parse::<ParseError>().unwrap().variant_name(), "Empty");
+}
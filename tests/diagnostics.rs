@@ -0,0 +1,110 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::{Diagnostics, Mabe, Severity};
+
+#[derive(Mabe)]
+enum Problem {
+    #[mabe(severity = "note")]
+    #[error("The message for Problem1.")]
+    Problem1,
+
+    #[mabe(severity = "warning")]
+    #[error("The message for Problem2. The value is {0}.")]
+    Problem2(u32),
+
+    #[error("The message for Problem3. The key is {key}.")]
+    #[debug("The debug message for Problem3.")]
+    Problem3 { key: String },
+
+    #[mabe(severity = "fatal")]
+    #[error("The message for Problem4.")]
+    Problem4,
+}
+
+#[derive(Mabe)]
+enum LoginProblem {
+    #[error("The password of {user} is invalid.")]
+    InvalidPassword {
+        user: String,
+        #[mabe(redact)]
+        password: String,
+    },
+}
+
+#[test]
+fn test() {
+    let mut diagnostics = Diagnostics::new();
+    assert!(diagnostics.is_empty());
+    assert_eq!(diagnostics.summary(), "no diagnostics");
+    assert_eq!(diagnostics.max_severity(), None);
+
+    assert!(diagnostics.push(Problem::Problem1));
+    assert!(diagnostics.push(Problem::Problem2(1)));
+    assert!(diagnostics.push(Problem::Problem3 { key: "a".to_string() }));
+    assert!(diagnostics.push(Problem::Problem2(2)));
+
+    // The errors with the same variant and fields are only added once.
+    assert!(!diagnostics.push(Problem::Problem2(1)));
+    diagnostics.extend([Problem::Problem3 { key: "a".to_string() }, Problem::Problem3 { key: "b".to_string() }]);
+
+    assert_eq!(diagnostics.len(), 5);
+    assert_eq!(diagnostics.count(Severity::Warning), 2);
+    assert_eq!(diagnostics.max_severity(), Some(Severity::Error));
+    assert!(diagnostics.has_errors());
+    assert_eq!(diagnostics.summary(), "2 errors, 2 warnings, 1 note");
+
+    // The errors are sorted from the most to the least severe, in the order of their insertion.
+    let states = diagnostics.iter().map(|problem| problem.state()).collect::<Vec<_>>();
+    assert_eq!(
+        states,
+        [
+            "Problem::Problem3 { key: \"a\" }",
+            "Problem::Problem3 { key: \"b\" }",
+            "Problem::Problem2(1)",
+            "Problem::Problem2(2)",
+            "Problem::Problem1",
+        ]
+    );
+
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(
+        diagnostics.to_string(),
+        "\nerrors:\n[error] The message for Problem3. The key is a.\n[debug] The debug message for Problem3.\n[error] The message for Problem3. The key is b.\n[debug] The debug message for Problem3.\nwarnings:\n[warning] The message for Problem2. The value is 1.\n[warning] The message for Problem2. The value is 2.\nnotes:\n[note] The message for Problem1.\n2 errors, 2 warnings, 1 note"
+    );
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(
+        diagnostics.to_string(),
+        "\nerrors:\n\u{1b}[1;31m[error]\u{1b}[0m The message for Problem3. The key is a.\n\u{1b}[1;32m[debug]\u{1b}[0m The debug message for Problem3.\n\u{1b}[1;31m[error]\u{1b}[0m The message for Problem3. The key is b.\n\u{1b}[1;32m[debug]\u{1b}[0m The debug message for Problem3.\nwarnings:\n\u{1b}[1;33m[warning]\u{1b}[0m The message for Problem2. The value is 1.\n\u{1b}[1;33m[warning]\u{1b}[0m The message for Problem2. The value is 2.\nnotes:\n\u{1b}[1;36m[note]\u{1b}[0m The message for Problem1.\n2 errors, 2 warnings, 1 note"
+    );
+
+    println!("{}", diagnostics);
+
+    // The collection is itself an error, which is only returned if it holds errors.
+    let result: Result<(), Box<dyn std::error::Error>> = diagnostics.into_result().map_err(Into::into);
+    assert!(result.is_err());
+
+    let warnings = [Problem::Problem1, Problem::Problem2(1)].into_iter().collect::<Diagnostics<_>>();
+    assert!(!warnings.has_errors());
+    assert!(warnings.into_result().is_ok());
+
+    let fatal = Diagnostics::from_iter([Problem::Problem1, Problem::Problem4, Problem::Problem4]);
+    assert_eq!(fatal.max_severity(), Some(Severity::Fatal));
+    assert_eq!(fatal.summary(), "1 fatal error, 1 note");
+
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(
+        fatal.to_string(),
+        "\nfatal errors:\n[fatal] The message for Problem4.\nnotes:\n[note] The message for Problem1.\n1 fatal error, 1 note"
+    );
+
+    assert_eq!(fatal.into_iter().count(), 2);
+
+    // The errors that only differ by their redacted fields are deduplicated, since their states are the same.
+    let mut logins = Diagnostics::new();
+    assert!(logins.push(LoginProblem::InvalidPassword { user: "root".to_string(), password: "a".to_string() }));
+    assert!(!logins.push(LoginProblem::InvalidPassword { user: "root".to_string(), password: "b".to_string() }));
+    assert!(logins.push(LoginProblem::InvalidPassword { user: "admin".to_string(), password: "a".to_string() }));
+    assert_eq!(logins.len(), 2);
+}
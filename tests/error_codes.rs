@@ -0,0 +1,80 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::{Mabe, MabeError};
+
+#[derive(Mabe)]
+enum Error {
+    #[mabe(code = "AUTH-0003")]
+    #[error("The error message for Code1.")]
+    Code1,
+
+    #[error("The error message for Code2. The value is {0}.")]
+    Code2(u32),
+
+    #[mabe(code = 12)]
+    #[error("The error message for Code3.")]
+    Code3,
+}
+
+// The variants that don't have a code are numbered after their discriminants.
+#[derive(Mabe)]
+#[mabe(code_prefix = "DB-", code_width = 3, show_code)]
+#[repr(u16)]
+enum DbError {
+    #[error("The error message for Db1.")]
+    #[debug("The debug message for Db1.")]
+    Db1 = 100,
+
+    #[error("The error message for Db2. The value is {0}.")]
+    Db2(u32),
+
+    #[mabe(code = "DB-LEGACY")]
+    #[error("The error message for Db3.")]
+    Db3,
+
+    #[error("The error message for Db4.")]
+    Db4 = 7,
+}
+
+#[test]
+fn test() {
+    let error1 = Error::Code1;
+    assert_eq!(error1.code(), Some("AUTH-0003"));
+    assert_eq!(Error::Code2(7).code(), None);
+    assert_eq!(Error::Code3.code(), Some("0012"));
+    assert_eq!(MabeError::code(&error1), Some("AUTH-0003"));
+    assert_eq!(Error::VARIANTS.iter().map(|info| info.code()).collect::<Vec<_>>(), [Some("AUTH-0003"), None, Some("0012")]);
+
+    // The code is only displayed if the `show_code` option is set.
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(error1.to_string(), "\n[error] The error message for Code1.");
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(error1.to_string(), "\n\u{1b}[1;31m[error]\u{1b}[0m The error message for Code1.");
+
+    println!("{}", error1);
+
+    let error2 = DbError::Db1;
+    assert_eq!(error2.code(), Some("DB-100"));
+    assert_eq!(DbError::Db2(7).code(), Some("DB-101"));
+    assert_eq!(DbError::Db3.code(), Some("DB-LEGACY"));
+    assert_eq!(DbError::Db4.code(), Some("DB-007"));
+
+    #[cfg(not(feature = "colorize"))]
+    {
+        assert_eq!(error2.to_string(), "\n[error] [DB-100] The error message for Db1.\n[debug] The debug message for Db1.");
+        assert_eq!(DbError::Db2(7).to_string(), "\n[error] [DB-101] The error message for Db2. The value is 7.");
+    }
+
+    #[cfg(feature = "colorize")]
+    {
+        assert_eq!(error2.to_string(), "\n\u{1b}[1;31m[error]\u{1b}[0m [DB-100] The error message for Db1.\n\u{1b}[1;32m[debug]\u{1b}[0m The debug message for Db1.");
+        assert_eq!(
+            DbError::Db2(7).to_string(),
+            "\n\u{1b}[1;31m[error]\u{1b}[0m [DB-101] The error message for Db2. The value is 7."
+        );
+    }
+
+    println!("{}", error2);
+}
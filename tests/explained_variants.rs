@@ -0,0 +1,53 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::{Mabe, MabeError};
+
+#[derive(Mabe)]
+enum Error {
+    #[mabe(code = "E0042")]
+    #[error("The error message for Explain1.")]
+    #[explain(include_str!("explanations/E0042.md"))]
+    Explain1,
+
+    #[mabe(code = "E0043")]
+    #[error("The error message for Explain2. The value is {0}.")]
+    #[explain("The explanation for Explain2.")]
+    Explain2(u32),
+
+    #[mabe(code = "E0044")]
+    #[error("The error message for Explain3.")]
+    Explain3,
+
+    #[error("The error message for Explain4.")]
+    #[explain("The explanation for Explain4.")]
+    Explain4,
+}
+
+#[test]
+fn test() {
+    let error1 = Error::Explain1;
+    assert_eq!(error1.explain(), Some(include_str!("explanations/E0042.md")));
+    assert_eq!(MabeError::explain(&error1), Some(include_str!("explanations/E0042.md")));
+
+    let error2 = Error::Explain2(7);
+    assert_eq!(error2.explain(), Some("The explanation for Explain2."));
+    assert_eq!(error2.variant_info().explanation(), Some("The explanation for Explain2."));
+    assert_eq!(Error::Explain3.explain(), None);
+    assert_eq!(Error::Explain4.explain(), Some("The explanation for Explain4."));
+
+    // The explanations can be looked up by code (e.g. by an `explain` subcommand of a CLI).
+    assert_eq!(Error::explain_code("E0042"), Some(include_str!("explanations/E0042.md")));
+    assert_eq!(Error::explain_code("E0043"), Some("The explanation for Explain2."));
+    assert_eq!(Error::explain_code("E0044"), None);
+    assert_eq!(Error::explain_code("E9999"), None);
+
+    // The explanation is not part of the displayed error.
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(error2.to_string(), "\n[error] The error message for Explain2. The value is 7.");
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(error2.to_string(), "\n\u{1b}[1;31m[error]\u{1b}[0m The error message for Explain2. The value is 7.");
+
+    println!("{}", error2);
+}
@@ -0,0 +1,73 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::{Mabe, MabeError};
+
+#[derive(Mabe)]
+#[mabe(docs_base = "https://docs.example.com/errors/{code}", show_url)]
+enum Error {
+    #[mabe(code = "E0001")]
+    #[error("The error message for Url1.")]
+    #[debug("The debug message for Url1.")]
+    Url1,
+
+    #[mabe(code = "E0002", url = "https://docs.example.com/{variant}")]
+    #[error("The error message for Url2. The value is {0}.")]
+    Url2(u32),
+
+    #[error("The error message for Url3.")]
+    Url3,
+}
+
+#[derive(Mabe)]
+enum PlainError {
+    #[mabe(url = "https://docs.example.com/plain")]
+    #[error("The error message for Plain1.")]
+    Plain1,
+}
+
+#[test]
+fn test() {
+    let error1 = Error::Url1;
+    assert_eq!(error1.help_url(), Some("https://docs.example.com/errors/E0001"));
+    assert_eq!(MabeError::help_url(&error1), Some("https://docs.example.com/errors/E0001"));
+
+    // The URL of the variant overrides the base URL of the enum, which can't be rendered for the variants without a code.
+    let error2 = Error::Url2(7);
+    assert_eq!(error2.help_url(), Some("https://docs.example.com/Url2"));
+    assert_eq!(Error::Url3.help_url(), None);
+
+    #[cfg(not(feature = "colorize"))]
+    {
+        assert_eq!(
+            error1.to_string(),
+            "\n[error] The error message for Url1.\n[debug] The debug message for Url1.\n[see] https://docs.example.com/errors/E0001"
+        );
+        assert_eq!(
+            error2.to_string(),
+            "\n[error] The error message for Url2. The value is 7.\n[see] https://docs.example.com/Url2"
+        );
+        assert_eq!(Error::Url3.to_string(), "\n[error] The error message for Url3.");
+    }
+
+    #[cfg(feature = "colorize")]
+    {
+        assert_eq!(error1.to_string(), "\n\u{1b}[1;31m[error]\u{1b}[0m The error message for Url1.\n\u{1b}[1;32m[debug]\u{1b}[0m The debug message for Url1.\n\u{1b}[1;34m[see]\u{1b}[0m https://docs.example.com/errors/E0001");
+        assert_eq!(error2.to_string(), "\n\u{1b}[1;31m[error]\u{1b}[0m The error message for Url2. The value is 7.\n\u{1b}[1;34m[see]\u{1b}[0m https://docs.example.com/Url2");
+        assert_eq!(Error::Url3.to_string(), "\n\u{1b}[1;31m[error]\u{1b}[0m The error message for Url3.");
+    }
+
+    println!("{}", error1);
+
+    // The URL is only displayed if the `show_url` option is set.
+    let error3 = PlainError::Plain1;
+    assert_eq!(error3.help_url(), Some("https://docs.example.com/plain"));
+
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(error3.to_string(), "\n[error] The error message for Plain1.");
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(error3.to_string(), "\n\u{1b}[1;31m[error]\u{1b}[0m The error message for Plain1.");
+
+    println!("{}", error3);
+}
@@ -0,0 +1,83 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+#![allow(dead_code, non_camel_case_types, unused_macros)]
+
+// Re-exports the crate like a facade crate would.
+pub mod facade {
+    pub use mabe;
+}
+
+// Shadows the items that the generated code refers to, which must not affect it.
+mod shadowed {
+    use crate::facade::mabe::Mabe;
+
+    macro_rules! format {
+        ($($t:tt)*) => {
+            compile_error!("The shadowed `format` macro was used.")
+        };
+    }
+
+    macro_rules! format_args {
+        ($($t:tt)*) => {
+            compile_error!("The shadowed `format_args` macro was used.")
+        };
+    }
+
+    macro_rules! write {
+        ($($t:tt)*) => {
+            compile_error!("The shadowed `write` macro was used.")
+        };
+    }
+
+    struct String;
+    struct Option;
+    struct Result;
+    struct FnMut;
+    struct str;
+    struct bool;
+    mod std {}
+    mod core {}
+
+    #[derive(Mabe)]
+    #[mabe(crate = "crate::facade::mabe")]
+    pub enum Error {
+        #[error("The error message for Hygiene1.")]
+        Hygiene1,
+
+        #[error("The error message for Hygiene2. The value is {0}.")]
+        #[debug("The debug message for Hygiene2. The value is {0:?}.")]
+        Hygiene2(u32),
+
+        #[error("The error message for Hygiene3. The value is {value}.")]
+        Hygiene3 {
+            value: u32,
+            #[mabe(redact)]
+            secret: u32,
+        },
+    }
+}
+
+use shadowed::Error;
+
+#[test]
+fn test() {
+    let error1 = Error::Hygiene1;
+    assert_eq!(error1.state(), "Error::Hygiene1");
+    assert_eq!(error1.error_str(), Some("The error message for Hygiene1."));
+
+    println!("{}", error1);
+
+    let error2 = Error::Hygiene2(7);
+    assert_eq!(error2.state(), "Error::Hygiene2(7)");
+    assert_eq!(error2.error(), "The error message for Hygiene2. The value is 7.");
+    assert_eq!(error2.debug(), "The debug message for Hygiene2. The value is 7.");
+
+    println!("{}", error2);
+
+    let error3 = Error::Hygiene3 { value: 7, secret: 42 };
+    assert_eq!(error3.state(), "Error::Hygiene3 { value: 7, secret: *** }");
+    assert_eq!(error3.error(), "The error message for Hygiene3. The value is 7.");
+
+    println!("{}", error3);
+}
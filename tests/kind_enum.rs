@@ -0,0 +1,55 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::Mabe;
+use std::collections::HashMap;
+
+#[derive(Mabe)]
+#[mabe(kind)]
+pub enum Error {
+    #[error("The error message for Kind1.")]
+    Kind1,
+
+    #[error("The error message for Kind2. The value is {0}.")]
+    Kind2(u32),
+
+    #[error("The error message for Kind3. The value is {value}.")]
+    #[debug("The debug message for Kind3.")]
+    Kind3 { value: String },
+
+    #[cfg(any())]
+    #[error("The error message for Kind4.")]
+    Kind4,
+}
+
+#[test]
+fn test() {
+    let error1 = Error::Kind1;
+    assert_eq!(error1.kind(), ErrorKind::Kind1);
+    assert_eq!(error1.kind().as_str(), "Kind1");
+    assert_eq!(error1.kind().to_string(), "Kind1");
+    assert_eq!(format!("{:?}", error1.kind()), "Kind1");
+
+    let error2 = Error::Kind2(7);
+    assert_eq!(error2.kind(), ErrorKind::Kind2);
+    assert_eq!(error2.kind().as_str(), "Kind2");
+    assert_eq!(error2.kind(), Error::Kind2(8).kind());
+    assert_ne!(error2.kind(), error1.kind());
+
+    let error3 = Error::Kind3 { value: "test".to_string() };
+    assert_eq!(error3.kind(), ErrorKind::Kind3);
+    assert_eq!(error3.kind().to_string(), "Kind3");
+
+    // The kinds can be used in `const` contexts and as keys in a map.
+    const KIND: ErrorKind = ErrorKind::Kind2;
+    const KIND_NAME: &str = KIND.as_str();
+    assert_eq!(KIND_NAME, "Kind2");
+
+    let mut counts = HashMap::<ErrorKind, usize>::new();
+    for error in [&error1, &error2, &Error::Kind2(8), &error3] {
+        *counts.entry(error.kind()).or_default() += 1;
+    }
+    assert_eq!(counts[&ErrorKind::Kind1], 1);
+    assert_eq!(counts[&ErrorKind::Kind2], 2);
+    assert_eq!(counts[&ErrorKind::Kind3], 1);
+}
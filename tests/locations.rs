@@ -0,0 +1,58 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::Mabe;
+use std::panic::Location;
+
+#[derive(Mabe)]
+enum DbError {
+    #[error("The query failed.")]
+    #[debug("The query was: {query}")]
+    QueryFailed {
+        query: String,
+        #[mabe(location)]
+        location: &'static Location<'static>,
+    },
+
+    #[error("The connection was lost.")]
+    ConnectionLost(#[mabe(from)] std::io::Error, #[mabe(location)] &'static Location<'static>),
+
+    #[error("The pool is exhausted.")]
+    PoolExhausted,
+}
+
+fn connect() -> Result<(), DbError> {
+    Err(std::io::Error::new(std::io::ErrorKind::BrokenPipe, "broken pipe"))?;
+    Ok(())
+}
+
+#[test]
+fn test() {
+    // The constructors record the location of their caller.
+    let (error, line) = (DbError::query_failed("SELECT 1".to_string()), line!());
+    let location = error.location().unwrap();
+    assert_eq!((location.file(), location.line()), (file!(), line));
+
+    // The `From` implementations record the location of the `?` operator.
+    let location = connect().unwrap_err().location().unwrap();
+    assert_eq!((location.file(), location.line()), (file!(), 25));
+    assert!(DbError::PoolExhausted.location().is_none());
+
+    // The locations are left out of the state of the variants, and rendered in an `at` line.
+    assert_eq!(error.state(), "DbError::QueryFailed { query: \"SELECT 1\", location: <location> }");
+
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(
+        error.to_string(),
+        format!("\n[error] The query failed.\n[debug] The query was: SELECT 1\n[at] tests/locations.rs:{}:26", line)
+    );
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "\n\u{1b}[1;31m[error]\u{1b}[0m The query failed.\n\u{1b}[1;32m[debug]\u{1b}[0m The query was: SELECT 1\n\u{1b}[1;90m[at]\u{1b}[0m tests/locations.rs:{}:26",
+            line
+        )
+    );
+}
@@ -0,0 +1,81 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::{Mabe, MabeError};
+
+#[derive(Mabe)]
+enum ServerError {
+    #[error("The error message for Server1.")]
+    #[debug("The debug message for Server1.")]
+    Server1,
+
+    #[error("The error message for Server2. The code is {code}.")]
+    Server2 { code: u16 },
+}
+
+#[derive(Mabe)]
+enum ClientError {
+    #[error("The error message for Client1. The value is {0}.")]
+    #[debug("The debug message for Client1.")]
+    Client1(u32),
+}
+
+// Renders any error through the runtime trait only.
+fn report<E: MabeError + ?Sized>(error: &E) -> String {
+    let mut msg = String::new();
+    error.write_error(&mut msg).unwrap();
+    format!("{}: {} ({})", error.variant_name(), msg, error.debug())
+}
+
+#[test]
+fn test() {
+    let error1 = ServerError::Server1;
+    assert_eq!(MabeError::variant_name(&error1), "Server1");
+    assert_eq!(MabeError::state(&error1), "ServerError::Server1");
+    assert_eq!(MabeError::error(&error1), "The error message for Server1.");
+    assert_eq!(MabeError::debug(&error1), "The debug message for Server1.");
+    assert_eq!(MabeError::error_str(&error1), Some("The error message for Server1."));
+    assert_eq!(MabeError::debug_str(&error1), Some("The debug message for Server1."));
+    assert_eq!(report(&error1), "Server1: The error message for Server1. (The debug message for Server1.)");
+
+    let error2 = ServerError::Server2 { code: 500 };
+    assert_eq!(MabeError::variant_name(&error2), "Server2");
+    assert_eq!(MabeError::state(&error2), "ServerError::Server2 { code: 500 }");
+    assert_eq!(MabeError::error_str(&error2), None);
+    assert_eq!(MabeError::debug_str(&error2), Some(""));
+    assert_eq!(report(&error2), "Server2: The error message for Server2. The code is 500. ()");
+
+    let error3 = ClientError::Client1(7);
+    assert_eq!(MabeError::variant_name(&error3), "Client1");
+    assert_eq!(report(&error3), "Client1: The error message for Client1. The value is 7. (The debug message for Client1.)");
+
+    // Stores errors of different enums together, and downcasts them back to their concrete types.
+    let mut errors: Vec<Box<dyn MabeError + Send + Sync>> = vec![Box::new(error1), Box::new(error2), Box::new(error3)];
+    assert_eq!(errors.iter().map(|e| e.variant_name()).collect::<Vec<_>>(), ["Server1", "Server2", "Client1"]);
+    assert_eq!(report(&*errors[2]), "Client1: The error message for Client1. The value is 7. (The debug message for Client1.)");
+
+    assert!(errors[0].is::<ServerError>());
+    assert!(!errors[0].is::<ClientError>());
+    assert!(matches!(errors[1].downcast_ref::<ServerError>(), Some(ServerError::Server2 { code: 500 })));
+    assert!(errors[1].downcast_ref::<ClientError>().is_none());
+
+    if let Some(ClientError::Client1(value)) = errors[2].downcast_mut::<ClientError>() {
+        *value = 8;
+    }
+    assert_eq!(errors[2].state(), "ClientError::Client1(8)");
+
+    // The trait objects can be used as standard errors (without trait upcasting, which requires Rust 1.86).
+    let error: &dyn std::error::Error = errors[0].as_error();
+    assert!(error.source().is_none());
+
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(error.to_string(), "\n[error] The error message for Server1.\n[debug] The debug message for Server1.");
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(
+        error.to_string(),
+        "\n\u{1b}[1;31m[error]\u{1b}[0m The error message for Server1.\n\u{1b}[1;32m[debug]\u{1b}[0m The debug message for Server1."
+    );
+
+    println!("{}", errors[0]);
+}
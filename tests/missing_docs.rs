@@ -0,0 +1,45 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+//! Checks that every public item generated by the derive macro is documented.
+
+#![deny(missing_docs)]
+
+use mabe::Mabe;
+use std::backtrace::Backtrace;
+use std::panic::Location;
+
+/// The errors of a documented public API.
+#[derive(Mabe)]
+#[mabe(kind, from_str, try_from_code, code_prefix = "API-", docs_base = "https://docs.example.com/{code}")]
+pub enum ApiError {
+    /// The request was invalid.
+    #[error("The request was invalid.")]
+    InvalidRequest,
+
+    /// The request timed out.
+    #[error("The request timed out after {0} seconds.")]
+    Timeout(u64),
+
+    /// The connection failed.
+    #[error("The connection failed.")]
+    ConnectionFailed {
+        /// The cause of the failure.
+        #[mabe(from, source)]
+        cause: std::io::Error,
+        /// The backtrace of the failure.
+        #[mabe(backtrace)]
+        backtrace: Backtrace,
+        /// The location of the failure.
+        #[mabe(location)]
+        location: &'static Location<'static>,
+    },
+}
+
+#[test]
+fn test() {
+    let error = ApiError::timeout(30);
+    assert!(error.is_timeout());
+    assert_eq!(error.kind().as_str(), "Timeout");
+    assert_eq!(error.code(), Some("API-0001"));
+}
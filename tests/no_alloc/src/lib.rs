@@ -0,0 +1,66 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+#![no_std]
+
+use core::error::Error;
+use core::fmt::{self, Write};
+use core::panic::PanicInfo;
+use mabe::Mabe;
+
+#[derive(Mabe)]
+#[mabe(no_alloc, kind, code_prefix = "SENSOR-")]
+pub enum SensorError {
+    #[error("The sensor {id} is not connected.")]
+    #[debug("Check the wiring of the sensor.")]
+    Disconnected { id: u8 },
+
+    #[error("The reading is out of range: {0}.")]
+    OutOfRange(i32),
+
+    #[error("The reading could not be formatted.")]
+    Format(#[mabe(source)] fmt::Error),
+}
+
+/// A fixed-size buffer that implements `fmt::Write` without allocating.
+struct Buffer<'a> {
+    bytes: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for Buffer<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Writes the message of the error of the given sensor into the given buffer, and returns the length of the message, or `-1`
+/// if the buffer is too small.
+///
+/// # Safety
+///
+/// The buffer must be valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn sensor_error_message(id: u8, buf: *mut u8, len: usize) -> isize {
+    let error = match id {
+        0 => SensorError::disconnected(id),
+        1 => SensorError::out_of_range(-40),
+        _ => SensorError::Format(fmt::Error),
+    };
+    let mut buffer = Buffer { bytes: core::slice::from_raw_parts_mut(buf, len), len: 0 };
+
+    match write!(buffer, "{} ({}, {:?})", error, error.kind(), error.code()) {
+        Ok(()) if error.is_disconnected() || error.as_out_of_range().is_some() || error.source().is_some() => {
+            buffer.len as isize
+        }
+        _ => -1,
+    }
+}
+
+#[panic_handler]
+fn panic(_: &PanicInfo) -> ! {
+    loop {}
+}
@@ -0,0 +1,106 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use core::fmt::Write;
+use mabe::Mabe;
+
+#[derive(Mabe)]
+#[mabe(no_std)]
+enum AllocError {
+    #[error("The error message for Alloc1.")]
+    #[debug("The debug message for Alloc1.")]
+    Alloc1,
+
+    #[error("The error message for Alloc2. The value is {0}.")]
+    Alloc2(u32),
+
+    #[error("The error message for Alloc3. The value is {value:?}.")]
+    Alloc3 { value: String },
+}
+
+#[derive(Mabe)]
+#[mabe(no_alloc)]
+enum CoreError {
+    #[error("The error message for Core1.")]
+    #[debug("The debug message for Core1.")]
+    Core1,
+
+    #[error("The error message for Core2. The value is {0}.")]
+    Core2(u32),
+}
+
+/// A fixed-size buffer that implements `fmt::Write` without allocating.
+struct Buffer {
+    bytes: [u8; 128],
+    len: usize,
+}
+
+impl Buffer {
+    fn new() -> Self {
+        Self { bytes: [0; 128], len: 0 }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        self.bytes.get_mut(self.len..end).ok_or(core::fmt::Error)?.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+fn assert_error<E: core::error::Error>(_: &E) {}
+
+#[test]
+fn test() {
+    let error1 = AllocError::Alloc1;
+    assert_eq!(error1.state(), "AllocError::Alloc1");
+    assert_eq!(error1.error(), "The error message for Alloc1.");
+    assert_eq!(error1.debug(), "The debug message for Alloc1.");
+
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(error1.to_string(), "\n[error] The error message for Alloc1.\n[debug] The debug message for Alloc1.");
+
+    assert_error(&error1);
+
+    let error2 = AllocError::Alloc2(7);
+    assert_eq!(error2.state(), "AllocError::Alloc2(7)");
+    assert_eq!(error2.error(), "The error message for Alloc2. The value is 7.");
+    assert_eq!(error2.debug(), "");
+
+    let error3 = AllocError::Alloc3 { value: "x".to_string() };
+    assert_eq!(error3.state(), "AllocError::Alloc3 { value: \"x\" }");
+    assert_eq!(error3.error(), "The error message for Alloc3. The value is \"x\".");
+
+    let error4 = CoreError::Core1;
+    assert_eq!(error4.error_str(), Some("The error message for Core1."));
+    assert_eq!(error4.debug_str(), Some("The debug message for Core1."));
+
+    let mut buffer = Buffer::new();
+    write!(buffer, "{:?}", error4).unwrap();
+    assert_eq!(buffer.as_str(), "CoreError::Core1");
+
+    assert_error(&error4);
+
+    let error5 = CoreError::Core2(7);
+    let mut buffer = Buffer::new();
+    error5.write_error(&mut buffer).unwrap();
+    assert_eq!(buffer.as_str(), "The error message for Core2. The value is 7.");
+
+    #[cfg(not(feature = "colorize"))]
+    {
+        let mut buffer = Buffer::new();
+        write!(buffer, "{}", error5).unwrap();
+        assert_eq!(buffer.as_str(), "\n[error] The error message for Core2. The value is 7.");
+    }
+}
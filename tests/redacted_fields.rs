@@ -0,0 +1,66 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::Mabe;
+
+#[derive(Mabe)]
+enum Error {
+    #[error("The error message for Redacted1. The user is {user}.")]
+    Redacted1 {
+        user: String,
+        #[mabe(redact)]
+        password: String,
+    },
+
+    #[error("The error message for Redacted2. The token is {1}.")]
+    #[debug("The debug message for Redacted2. The code is {0}.")]
+    Redacted2(u32, #[mabe(redact = "<hidden>", interpolate)] String),
+
+    #[error("The error message for Redacted3.")]
+    Redacted3(#[mabe(redact = "[REDACTED]")] String),
+
+    #[error("The error message for Redacted4. The key is {0:?}.")]
+    #[debug("The debug message for Redacted4. The secret is {1:>8}.")]
+    Redacted4(#[mabe(redact, interpolate)] String, #[mabe(redact)] String),
+}
+
+#[test]
+fn test() {
+    let error1 = Error::Redacted1 { user: "admin".to_string(), password: "hunter2".to_string() };
+    assert_eq!(error1.state(), "Error::Redacted1 { user: \"admin\", password: *** }");
+    assert_eq!(error1.error(), "The error message for Redacted1. The user is admin.");
+    assert_eq!(error1.debug(), "");
+    assert!(!format!("{:#?}", error1).contains("hunter2"));
+
+    println!("{}", error1);
+
+    let error2 = Error::Redacted2(42, "secret".to_string());
+    assert_eq!(error2.state(), "Error::Redacted2(42, <hidden>)");
+    assert_eq!(error2.error(), "The error message for Redacted2. The token is <hidden>.");
+    assert_eq!(error2.debug(), "The debug message for Redacted2. The code is 42.");
+
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(
+        error2.to_string(),
+        "\n[error] The error message for Redacted2. The token is <hidden>.\n[debug] The debug message for Redacted2. The code is 42."
+    );
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(error2.to_string(), "\n\u{1b}[1;31m[error]\u{1b}[0m The error message for Redacted2. The token is <hidden>.\n\u{1b}[1;32m[debug]\u{1b}[0m The debug message for Redacted2. The code is 42.");
+
+    println!("{}", error2);
+
+    let error3 = Error::Redacted3("secret".to_string());
+    assert_eq!(error3.state(), "Error::Redacted3([REDACTED])");
+    assert_eq!(error3.error(), "The error message for Redacted3.");
+    assert_eq!(error3.debug(), "");
+
+    println!("{}", error3);
+
+    let error4 = Error::Redacted4("key".to_string(), "secret".to_string());
+    assert_eq!(error4.state(), "Error::Redacted4(***, ***)");
+    assert_eq!(error4.error(), "The error message for Redacted4. The key is ***.");
+    assert_eq!(error4.debug(), "The debug message for Redacted4. The secret is ***.");
+
+    println!("{}", error4);
+}
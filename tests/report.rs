@@ -0,0 +1,115 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::{Cause, Mabe, MabeError, Report};
+use std::error::Error;
+use std::fmt;
+
+#[derive(Mabe)]
+enum ConfigError {
+    #[error("The config file could not be read.")]
+    #[debug("Check the path of the config file.")]
+    Unreadable(#[mabe(source)] std::io::Error),
+
+    #[error("The config file is invalid.")]
+    Invalid {
+        #[mabe(source)]
+        source: Box<dyn Error + Send + Sync>,
+    },
+
+    #[error("The config file is missing.")]
+    Missing,
+}
+
+#[derive(Mabe)]
+enum AppError {
+    #[error("The app could not start.")]
+    #[debug("The startup failed at step {step}.")]
+    Startup {
+        step: u8,
+        #[mabe(source)]
+        cause: ConfigError,
+    },
+
+    #[error("The app could not load a plugin.")]
+    Plugin(#[mabe(source)] Looping),
+}
+
+/// A foreign error that is its own source.
+#[derive(Debug)]
+struct Looping;
+
+impl fmt::Display for Looping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("The plugin is looping.\nIt never ends.")
+    }
+}
+
+impl Error for Looping {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self)
+    }
+}
+
+#[test]
+fn test() {
+    let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "no such file");
+    let error = AppError::Startup { step: 2, cause: ConfigError::Unreadable(io_error) };
+
+    // The source is returned by the `Error` trait, and the runtime trait tells whether it derives `Mabe`.
+    assert!(error.source().unwrap().downcast_ref::<ConfigError>().is_some());
+    assert!(matches!(error.mabe_source(), Some(Cause::Mabe(cause)) if cause.variant_name() == "Unreadable"));
+    assert!(matches!(error.mabe_source().unwrap().next(), Some(Cause::Foreign(_))));
+    assert!(error.mabe_source().unwrap().next().unwrap().next().is_none());
+    assert!(ConfigError::Missing.source().is_none());
+    assert!(ConfigError::Missing.mabe_source().is_none());
+
+    let report = Report::new(error);
+
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(
+        report.to_string(),
+        "\n[error] The app could not start.\n[debug] The startup failed at step 2.\n  [cause] The config file could not be read.\n  [debug] Check the path of the config file.\n    [cause] no such file"
+    );
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(
+        report.to_string(),
+        "\n\u{1b}[1;31m[error]\u{1b}[0m The app could not start.\n\u{1b}[1;32m[debug]\u{1b}[0m The startup failed at step 2.\n  \u{1b}[1;31m[cause]\u{1b}[0m The config file could not be read.\n  \u{1b}[1;32m[debug]\u{1b}[0m Check the path of the config file.\n    \u{1b}[1;31m[cause]\u{1b}[0m no such file"
+    );
+
+    println!("{}", report);
+
+    // The number of rendered causes is limited.
+    let report = report.max_depth(1);
+    assert!(report.to_string().ends_with("\n    [cause] ... (the remaining causes are omitted)") != cfg!(feature = "colorize"));
+    assert!(
+        report.to_string().ends_with("[cause]\u{1b}[0m ... (the remaining causes are omitted)") == cfg!(feature = "colorize")
+    );
+    assert_eq!(report.to_string().lines().count(), 6);
+
+    // The boxed sources are rendered like any other foreign error.
+    let source = Box::<dyn Error + Send + Sync>::from("expected a table");
+    let error = AppError::Startup { step: 1, cause: ConfigError::Invalid { source } };
+
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(
+        Report::from(error).to_string(),
+        "\n[error] The app could not start.\n[debug] The startup failed at step 1.\n  [cause] The config file is invalid.\n    [cause] expected a table"
+    );
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(
+        Report::from(error).to_string(),
+        "\n\u{1b}[1;31m[error]\u{1b}[0m The app could not start.\n\u{1b}[1;32m[debug]\u{1b}[0m The startup failed at step 1.\n  \u{1b}[1;31m[cause]\u{1b}[0m The config file is invalid.\n    \u{1b}[1;31m[cause]\u{1b}[0m expected a table"
+    );
+
+    // The rendering stops when the chain of causes loops back (possibly after repeating the first cause of the loop, since the
+    // same type can have several vtables), and the lines of the causes are indented.
+    let report = Report::new(AppError::Plugin(Looping)).to_string();
+    assert!(report.contains("The app could not load a plugin.\n"));
+    assert!(report.contains("[cause]\u{1b}[0m The plugin is looping.\n  It never ends.\n") == cfg!(feature = "colorize"));
+    assert!(report.contains("[cause] The plugin is looping.\n  It never ends.\n") != cfg!(feature = "colorize"));
+    assert!(report.ends_with(" ... (the chain of causes loops back)"));
+    assert!(report.lines().count() <= 7);
+}
@@ -0,0 +1,69 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::{Mabe, MabeError, Severity};
+
+#[derive(Mabe)]
+#[mabe(severity = "warning")]
+enum Lint {
+    #[error("The message for Severity1.")]
+    #[debug("The debug message for Severity1.")]
+    Severity1,
+
+    #[mabe(severity = "note")]
+    #[error("The message for Severity2. The value is {0}.")]
+    Severity2(u32),
+
+    #[mabe(severity = "error")]
+    #[error("The message for Severity3.")]
+    Severity3,
+
+    #[mabe(severity = "fatal")]
+    #[error("The message for Severity4.")]
+    Severity4,
+}
+
+#[derive(Mabe)]
+#[mabe(severity = "note")]
+enum Note {
+    #[error("The message for Note1.")]
+    Note1,
+}
+
+#[test]
+fn test() {
+    assert_eq!(Lint::Severity1.severity(), Severity::Warning);
+    assert_eq!(Lint::Severity2(7).severity(), Severity::Note);
+    assert_eq!(Lint::Severity3.severity(), Severity::Error);
+    assert_eq!(MabeError::severity(&Lint::Severity4), Severity::Fatal);
+    assert_eq!(Note::Note1.severity(), Severity::Note);
+
+    // The severities are ordered from the least to the most severe.
+    assert!(Severity::Note < Severity::Warning && Severity::Warning < Severity::Error && Severity::Error < Severity::Fatal);
+    assert_eq!(Severity::default(), Severity::Error);
+    assert_eq!(Severity::Warning.as_str(), "warning");
+    assert_eq!(Severity::Fatal.to_string(), "fatal");
+
+    #[cfg(not(feature = "colorize"))]
+    {
+        assert_eq!(
+            Lint::Severity1.to_string(),
+            "\n[warning] The message for Severity1.\n[debug] The debug message for Severity1."
+        );
+        assert_eq!(Lint::Severity2(7).to_string(), "\n[note] The message for Severity2. The value is 7.");
+        assert_eq!(Lint::Severity3.to_string(), "\n[error] The message for Severity3.");
+        assert_eq!(Lint::Severity4.to_string(), "\n[fatal] The message for Severity4.");
+        assert_eq!(Note::Note1.to_string(), "\n[note] The message for Note1.");
+    }
+
+    #[cfg(feature = "colorize")]
+    {
+        assert_eq!(Lint::Severity1.to_string(), "\n\u{1b}[1;33m[warning]\u{1b}[0m The message for Severity1.\n\u{1b}[1;32m[debug]\u{1b}[0m The debug message for Severity1.");
+        assert_eq!(Lint::Severity2(7).to_string(), "\n\u{1b}[1;36m[note]\u{1b}[0m The message for Severity2. The value is 7.");
+        assert_eq!(Lint::Severity3.to_string(), "\n\u{1b}[1;31m[error]\u{1b}[0m The message for Severity3.");
+        assert_eq!(Lint::Severity4.to_string(), "\n\u{1b}[1;35m[fatal]\u{1b}[0m The message for Severity4.");
+        assert_eq!(Note::Note1.to_string(), "\n\u{1b}[1;36m[note]\u{1b}[0m The message for Note1.");
+    }
+
+    println!("{}", Lint::Severity1);
+}
@@ -0,0 +1,90 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::{render_snippet, Mabe};
+use std::ops::Range;
+
+#[derive(Mabe)]
+enum ParseError {
+    #[error("The error message for Snippet1.")]
+    #[debug("The debug message for Snippet1.")]
+    Snippet1 {
+        #[mabe(source_code)]
+        source: String,
+        #[mabe(label = "expected `;` here")]
+        span: Range<usize>,
+    },
+
+    #[error("The error message for Snippet2.")]
+    Snippet2(#[mabe(source_code)] &'static str, #[mabe(label = "first")] Range<usize>, #[mabe(label)] Range<usize>),
+
+    #[error("The error message for Snippet3. The value is {0}.")]
+    Snippet3(u32),
+}
+
+fn snippet(source: &str, labels: &[(Range<usize>, &str)]) -> String {
+    let mut snippet = String::new();
+    render_snippet(&mut snippet, source, labels).unwrap();
+    snippet
+}
+
+#[test]
+fn test() {
+    // Example 1: Single-line span.
+    let error1 = ParseError::Snippet1 { source: "let x = 5\nlet y = 6;".to_string(), span: 9..9 };
+
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(
+        error1.to_string(),
+        "\n[error] The error message for Snippet1.\n  |\n1 | let x = 5\n  |          ^ expected `;` here\n[debug] The debug message for Snippet1."
+    );
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(
+        error1.to_string(),
+        "\n\u{1b}[1;31m[error]\u{1b}[0m The error message for Snippet1.\n  |\n1 | let x = 5\n  |          ^ expected `;` here\n\u{1b}[1;32m[debug]\u{1b}[0m The debug message for Snippet1."
+    );
+
+    println!("{}", error1);
+
+    // Example 2: Several labels in a tuple variant, with a gap between the labeled lines.
+    let error2 = ParseError::Snippet2("fn main() {\n    a();\n    b();\n}", 3..7, 30..31);
+
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(
+        error2.to_string(),
+        "\n[error] The error message for Snippet2.\n  |\n1 | fn main() {\n  |    ^^^^ first\n...\n4 | }\n  | ^"
+    );
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(error2.to_string(), "\n\u{1b}[1;31m[error]\u{1b}[0m The error message for Snippet2.\n  |\n1 | fn main() {\n  |    ^^^^ first\n...\n4 | }\n  | ^");
+
+    println!("{}", error2);
+
+    // Example 3: No snippet for the variants without source code.
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(ParseError::Snippet3(7).to_string(), "\n[error] The error message for Snippet3. The value is 7.");
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(
+        ParseError::Snippet3(7).to_string(),
+        "\n\u{1b}[1;31m[error]\u{1b}[0m The error message for Snippet3. The value is 7."
+    );
+
+    // Example 4: Tabs and multi-byte characters.
+    assert_eq!(snippet("\tlet é = ü;", &[(5..7, "here")]), "\n  |\n1 |     let é = ü;\n  |         ^ here");
+    assert_eq!(snippet("\"€€\" + x", &[(1..7, "euros"), (11..12, "")]), "\n  |\n1 | \"€€\" + x\n  |  ^^ euros\n  |        ^");
+
+    // Example 5: Multi-line span, with the label on its last line.
+    let source5 = "let s = \"abc\ndef\nghi\";";
+    let expected5 = "\n  |\n1 | let s = \"abc\n  |         ^^^^\n2 | def\n  | ^^^\n3 | ghi\";\n  | ^^^^ unterminated";
+    assert_eq!(snippet(source5, &[(8..21, "unterminated")]), expected5);
+
+    // Example 6: Spans that are out of bounds or not on character boundaries are clamped.
+    assert_eq!(snippet("é", &[(1..100, "clamped")]), "\n  |\n1 | é\n  | ^ clamped");
+    assert_eq!(snippet("abc", &[]), "");
+
+    // Example 7: Line numbers with more than one digit.
+    let source7 = "\n".repeat(9) + "x";
+    assert_eq!(snippet(&source7, &[(9..10, "ten")]), "\n   |\n10 | x\n   | ^ ten");
+}
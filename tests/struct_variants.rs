@@ -1,8 +1,17 @@
 // Copyright 2024 Amon Rayfa.
 // SPDX-License-Identifier: Apache-2.0.
 
+#![allow(clippy::approx_constant)]
+
 use mabe::Mabe;
 
+#[allow(dead_code)]
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
 #[derive(Mabe)]
 enum Error {
     #[error("The error message for Struct1. The placeholders are: {0}, {msg}, {{0}}, {None}, and {{{None}}}.")]
@@ -16,14 +25,24 @@ enum Error {
     #[error("The error message for Struct3. The placeholders are: {{0}}, {ratio}, {height}, {2}, and {{{--1}}}.")]
     #[debug("The debug message for Struct3. The placeholders are: {comment}, {{{0}}}, {ratio}, and {{ratio}}.")]
     Struct3 { comment: String, height: usize, ratio: f64 },
+
+    #[error("The error message for Struct4. The placeholders are: {point:?} and {label:>6}.")]
+    Struct4 { point: Point, label: String },
 }
 
 #[test]
 fn test() {
     let error1 = Error::Struct1 { msg: "Something...".to_string() };
-    assert_eq!(error1.state(), "Error::Struct1 { msg: Something... }");
+    assert_eq!(error1.state(), "Error::Struct1 { msg: \"Something...\" }");
     assert_eq!(error1.error(), "The error message for Struct1. The placeholders are: 0, Something..., {0}, None, and {None}.");
     assert_eq!(error1.debug(), "The debug message for Struct1. The placeholders are: 007, 420, 000, and false.");
+    assert_eq!(error1.error_str(), None);
+    assert_eq!(error1.debug_str(), Some("The debug message for Struct1. The placeholders are: 007, 420, 000, and false."));
+
+    let mut buffer = String::new();
+    error1.write_error(&mut buffer).unwrap();
+    error1.write_debug(&mut buffer).unwrap();
+    assert_eq!(buffer, error1.error() + &error1.debug());
 
     #[cfg(not(feature = "colorize"))]
     assert_eq!(error1.to_string(), "\n[error] The error message for Struct1. The placeholders are: 0, Something..., {0}, None, and {None}.\n[debug] The debug message for Struct1. The placeholders are: 007, 420, 000, and false.");
@@ -47,7 +66,7 @@ fn test() {
     println!("{}", error2);
 
     let error3 = Error::Struct3 { comment: "msg".to_string(), height: 100487, ratio: 3.1415 };
-    assert_eq!(error3.state(), "Error::Struct3 { comment: msg, height: 100487, ratio: 3.1415 }");
+    assert_eq!(error3.state(), "Error::Struct3 { comment: \"msg\", height: 100487, ratio: 3.1415 }");
     assert_eq!(error3.error(), "The error message for Struct3. The placeholders are: {0}, 3.1415, 100487, 2, and {--1}.");
     assert_eq!(error3.debug(), "The debug message for Struct3. The placeholders are: msg, {0}, 3.1415, and {ratio}.");
 
@@ -58,4 +77,16 @@ fn test() {
     assert_eq!(error3.to_string(), "\n\u{1b}[1;31m[error]\u{1b}[0m The error message for Struct3. The placeholders are: {0}, 3.1415, 100487, 2, and {--1}.\n\u{1b}[1;32m[debug]\u{1b}[0m The debug message for Struct3. The placeholders are: msg, {0}, 3.1415, and {ratio}.");
 
     println!("{}", error3);
+
+    let error4 = Error::Struct4 { point: Point { x: 1, y: -2 }, label: "abc".to_string() };
+    assert_eq!(error4.state(), "Error::Struct4 { point: Point { x: 1, y: -2 }, label: \"abc\" }");
+    assert_eq!(format!("{:?}", error4), error4.state());
+    assert_eq!(
+        format!("{:#?}", error4),
+        "Error::Struct4 {\n    point: Point {\n        x: 1,\n        y: -2,\n    },\n    label: \"abc\",\n}"
+    );
+    assert_eq!(error4.error(), "The error message for Struct4. The placeholders are: Point { x: 1, y: -2 } and    abc.");
+    assert_eq!(error4.debug(), "");
+
+    println!("{}", error4);
 }
@@ -1,8 +1,14 @@
 // Copyright 2024 Amon Rayfa.
 // SPDX-License-Identifier: Apache-2.0.
 
+#![allow(clippy::approx_constant)]
+
 use mabe::Mabe;
 
+#[allow(dead_code)]
+#[derive(Debug)]
+struct Point(i32, i32);
+
 #[derive(Mabe)]
 enum Error {
     #[error("The error message for Tuple1. The placeholders are: {0}, {y}, {{0}}, {msg}, and {{{cause}}}.")]
@@ -16,12 +22,19 @@ enum Error {
     #[error("The error message for Tuple3. The placeholders are: {{0}}, {2}, {1}, {2}, and {{{--1}}}.")]
     #[debug("The debug message for Tuple3. The placeholders are: {1}, {{{0}}}, {2}, and {{1}}.")]
     Tuple3(String, usize, f64),
+
+    #[error("The error message for Tuple4. The placeholders are: {0:?} and {1:.2}.")]
+    Tuple4(Point, f64),
+
+    #[error("The error message for Tuple5.")]
+    #[debug("{0}")]
+    Tuple5(String),
 }
 
 #[test]
 fn test() {
     let error1 = Error::Tuple1("x".to_string());
-    assert_eq!(error1.state(), "Error::Tuple1(x)");
+    assert_eq!(error1.state(), "Error::Tuple1(\"x\")");
     assert_eq!(error1.error(), "The error message for Tuple1. The placeholders are: x, y, {0}, msg, and {cause}.");
     assert_eq!(error1.debug(), "The debug message for Tuple1. The placeholders are: 007, 420, 000, and false.");
 
@@ -47,7 +60,7 @@ fn test() {
     println!("{}", error2);
 
     let error3 = Error::Tuple3("msg".to_string(), 100487, 3.1415);
-    assert_eq!(error3.state(), "Error::Tuple3(msg, 100487, 3.1415)");
+    assert_eq!(error3.state(), "Error::Tuple3(\"msg\", 100487, 3.1415)");
     assert_eq!(error3.error(), "The error message for Tuple3. The placeholders are: {0}, 3.1415, 100487, 3.1415, and {--1}.");
     assert_eq!(error3.debug(), "The debug message for Tuple3. The placeholders are: 100487, {msg}, 3.1415, and {1}.");
 
@@ -58,4 +71,34 @@ fn test() {
     assert_eq!(error3.to_string(), "\n\u{1b}[1;31m[error]\u{1b}[0m The error message for Tuple3. The placeholders are: {0}, 3.1415, 100487, 3.1415, and {--1}.\n\u{1b}[1;32m[debug]\u{1b}[0m The debug message for Tuple3. The placeholders are: 100487, {msg}, 3.1415, and {1}.");
 
     println!("{}", error3);
+
+    let error4 = Error::Tuple4(Point(1, -2), 3.1415);
+    assert_eq!(error4.state(), "Error::Tuple4(Point(1, -2), 3.1415)");
+    assert_eq!(format!("{:?}", error4), error4.state());
+    assert_eq!(format!("{:#?}", error4), "Error::Tuple4(\n    Point(\n        1,\n        -2,\n    ),\n    3.1415,\n)");
+    assert_eq!(error4.error(), "The error message for Tuple4. The placeholders are: Point(1, -2) and 3.14.");
+    assert_eq!(error4.debug(), "");
+
+    println!("{}", error4);
+
+    // The debug line is skipped if the debug message renders to an empty string.
+    let error5 = Error::Tuple5(String::new());
+    assert_eq!(error5.debug(), "");
+    assert_eq!(error5.debug_str(), None);
+
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(error5.to_string(), "\n[error] The error message for Tuple5.");
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(error5.to_string(), "\n\u{1b}[1;31m[error]\u{1b}[0m The error message for Tuple5.");
+
+    let error5 = Error::Tuple5("The debug message for Tuple5.".to_string());
+
+    #[cfg(not(feature = "colorize"))]
+    assert_eq!(error5.to_string(), "\n[error] The error message for Tuple5.\n[debug] The debug message for Tuple5.");
+
+    #[cfg(feature = "colorize")]
+    assert_eq!(error5.to_string(), "\n\u{1b}[1;31m[error]\u{1b}[0m The error message for Tuple5.\n\u{1b}[1;32m[debug]\u{1b}[0m The debug message for Tuple5.");
+
+    println!("{}", error5);
 }
@@ -55,6 +55,8 @@ fn test() {
     assert_eq!(error1.state(), "Error::Unit1");
     assert_eq!(error1.error(), "The error message for Unit1. This is a test.");
     assert_eq!(error1.debug(), "");
+    assert_eq!(error1.error_str(), Some("The error message for Unit1. This is a test."));
+    assert_eq!(error1.debug_str(), Some(""));
 
     #[cfg(not(feature = "colorize"))]
     assert_eq!(error1.to_string(), "\n[error] The error message for Unit1. This is a test.");
@@ -68,6 +70,8 @@ fn test() {
     assert_eq!(error2.state(), "Error::Unit2");
     assert_eq!(error2.error(), "The error message for Unit2. This is a {test}.");
     assert_eq!(error2.debug(), "The debug message for Unit2. This is a {test}.");
+    assert_eq!(error2.error_str(), Some("The error message for Unit2. This is a {test}."));
+    assert_eq!(error2.debug_str(), Some("The debug message for Unit2. This is a {test}."));
 
     #[cfg(not(feature = "colorize"))]
     assert_eq!(
@@ -0,0 +1,93 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::Mabe;
+
+#[derive(Mabe)]
+enum Error {
+    #[error("The error message for Unused1. The query is {query}.")]
+    Unused1 {
+        query: String,
+        #[mabe(skip)]
+        request_id: u64,
+    },
+
+    #[error("The error message for Unused2. The query is {0}.")]
+    Unused2(String, #[mabe(skip)] u64),
+
+    #[error("The error message for Unused3. The query is {query}.")]
+    Unused3 { query: String, _elapsed_ms: u128 },
+}
+
+#[derive(Mabe)]
+#[mabe(unused_fields = "allow")]
+enum AllowedError {
+    #[error("The error message for Allowed1.")]
+    #[debug("The debug message for Allowed1.")]
+    Allowed1 { request_id: u64 },
+
+    #[error("The error message for Allowed2. The query is {1}.")]
+    Allowed2(u64, String),
+}
+
+// The `allow` attribute silences the warning emitted for the unused field.
+#[derive(Mabe)]
+#[mabe(unused_fields = "warn")]
+enum WarnedError {
+    #[error("The error message for Warned1.")]
+    Warned1 {
+        #[allow(deprecated)]
+        request_id: u64,
+    },
+
+    #[error("The error message for Warned2.")]
+    #[allow(deprecated)]
+    Warned2(u64),
+}
+
+#[test]
+fn test() {
+    let error1 = Error::Unused1 { query: "SELECT".to_string(), request_id: 7 };
+    assert_eq!(error1.state(), "Error::Unused1 { query: \"SELECT\", request_id: 7 }");
+    assert_eq!(error1.error(), "The error message for Unused1. The query is SELECT.");
+    assert_eq!(error1.debug(), "");
+
+    println!("{}", error1);
+
+    let error2 = Error::Unused2("SELECT".to_string(), 7);
+    assert_eq!(error2.state(), "Error::Unused2(\"SELECT\", 7)");
+    assert_eq!(error2.error(), "The error message for Unused2. The query is SELECT.");
+
+    println!("{}", error2);
+
+    let error3 = Error::Unused3 { query: "SELECT".to_string(), _elapsed_ms: 12 };
+    assert_eq!(error3.state(), "Error::Unused3 { query: \"SELECT\", _elapsed_ms: 12 }");
+    assert_eq!(error3.error(), "The error message for Unused3. The query is SELECT.");
+
+    println!("{}", error3);
+
+    let error4 = AllowedError::Allowed1 { request_id: 7 };
+    assert_eq!(error4.state(), "AllowedError::Allowed1 { request_id: 7 }");
+    assert_eq!(error4.error(), "The error message for Allowed1.");
+    assert_eq!(error4.debug(), "The debug message for Allowed1.");
+
+    println!("{}", error4);
+
+    let error5 = AllowedError::Allowed2(7, "SELECT".to_string());
+    assert_eq!(error5.state(), "AllowedError::Allowed2(7, \"SELECT\")");
+    assert_eq!(error5.error(), "The error message for Allowed2. The query is SELECT.");
+
+    println!("{}", error5);
+
+    let error6 = WarnedError::Warned1 { request_id: 7 };
+    assert_eq!(error6.state(), "WarnedError::Warned1 { request_id: 7 }");
+    assert_eq!(error6.error(), "The error message for Warned1.");
+
+    println!("{}", error6);
+
+    let error7 = WarnedError::Warned2(7);
+    assert_eq!(error7.state(), "WarnedError::Warned2(7)");
+    assert_eq!(error7.error(), "The error message for Warned2.");
+
+    println!("{}", error7);
+}
@@ -0,0 +1,54 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::Mabe;
+
+#[derive(Mabe)]
+#[mabe(try_from_code)]
+enum ServerError {
+    #[error("The connection was lost: {cause}.")]
+    #[debug("Retrying in {retry_in} seconds.")]
+    ConnectionLost { cause: String, retry_in: u32 },
+
+    #[error("The request timed out after {0} seconds.")]
+    Timeout(u64),
+
+    #[error("The server is shutting down.")]
+    ShuttingDown,
+
+    // The `code` constructor and the `as_code` accessor would clash with the generated `code` and `as_code` methods.
+    #[mabe(code = 7, no_constructor, no_accessors)]
+    #[error("The server returned the code {0}.")]
+    Code(u32),
+
+    // The accessors would clash with a method that is not generated.
+    #[mabe(no_accessors)]
+    #[error("The request can be retried.")]
+    Retryable,
+}
+
+impl ServerError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, Self::Retryable | Self::Timeout(_))
+    }
+}
+
+#[test]
+fn test() {
+    let error = ServerError::connection_lost("timeout", 10);
+    assert!(error.is_connection_lost());
+    assert!(!error.is_timeout() && !error.is_shutting_down());
+    assert_eq!(error.as_connection_lost(), Some((&"timeout".to_string(), &10)));
+    assert_eq!(error.as_timeout(), None);
+
+    // The variants with a single field return a reference to it.
+    let error = ServerError::timeout(30);
+    assert_eq!(error.as_timeout(), Some(&30));
+    assert_eq!(error.as_connection_lost(), None);
+
+    assert_eq!(ServerError::Code(500).as_code(), Some(7));
+    assert!(ServerError::Retryable.is_retryable() && ServerError::Timeout(30).is_retryable());
+
+    // The predicates can be used in constant contexts.
+    const { assert!(ServerError::ShuttingDown.is_shutting_down()) };
+}
@@ -0,0 +1,56 @@
+// Copyright 2024 Amon Rayfa.
+// SPDX-License-Identifier: Apache-2.0.
+
+use mabe::{Mabe, MabeError, VariantInfo};
+
+#[derive(Mabe)]
+enum Error {
+    #[error("The error message for Info1.")]
+    Info1,
+
+    #[error("The error message for Info2. The values are {1}, {0:?} and {1}.")]
+    #[debug("The debug message for Info2. The value is {{0}}.")]
+    Info2(u32, String),
+
+    #[error("The error message for Info3. The value is {value:>8}.")]
+    #[debug("The debug message for Info3. The cause is {cause}.")]
+    Info3 { value: u32, cause: String },
+
+    #[cfg(any())]
+    #[error("The error message for Info4.")]
+    Info4,
+}
+
+// The metadata is usable in `const` contexts.
+const NAMES: &[&str] = Error::VARIANT_NAMES;
+const COUNT: usize = Error::VARIANT_COUNT;
+const INFO: &VariantInfo = Error::Info1.variant_info();
+
+#[test]
+fn test() {
+    assert_eq!(NAMES, ["Info1", "Info2", "Info3"]);
+    assert_eq!(COUNT, 3);
+    assert_eq!(Error::VARIANTS.len(), 3);
+    assert_eq!(Error::VARIANTS.iter().map(|info| info.name()).collect::<Vec<_>>(), NAMES);
+
+    assert_eq!(INFO.name(), "Info1");
+    assert_eq!(INFO.error_template(), "The error message for Info1.");
+    assert_eq!(INFO.debug_template(), "");
+    assert!(INFO.error_placeholders().is_empty());
+    assert!(INFO.debug_placeholders().is_empty());
+
+    let error2 = Error::Info2(7, "test".to_string());
+    assert_eq!(error2.variant_name(), "Info2");
+    assert_eq!(error2.variant_info(), &Error::VARIANTS[1]);
+    assert_eq!(error2.variant_info().error_template(), "The error message for Info2. The values are {1}, {0:?} and {1}.");
+    assert_eq!(error2.variant_info().debug_template(), "The debug message for Info2. The value is {{0}}.");
+    assert_eq!(error2.variant_info().error_placeholders(), ["1", "0"]);
+    assert!(error2.variant_info().debug_placeholders().is_empty());
+
+    let error3 = Error::Info3 { value: 7, cause: "test".to_string() };
+    assert_eq!(error3.variant_name(), "Info3");
+    assert_eq!(MabeError::variant_info(&error3), &Error::VARIANTS[2]);
+    assert_eq!(error3.variant_info().error_template(), "The error message for Info3. The value is {value:>8}.");
+    assert_eq!(error3.variant_info().error_placeholders(), ["value"]);
+    assert_eq!(error3.variant_info().debug_placeholders(), ["cause"]);
+}